@@ -8,15 +8,16 @@ BUCKET_NAME = "zk-benchmarking"
 
 def main():
     subprocess.run([
-        "aws", "s3", "cp", "s3://" + BUCKET_NAME "/",
+        "aws", "s3", "cp", "s3://" + BUCKET_NAME + "/",
         "results/", "--recursive"
     ])
     with open("output.csv", 'w', newline='') as write_file:
         writer = csv.writer(write_file)
         writer.writerow([
-            "Instance type + job name + job size", "Proof duration",
-            "Verify duration", "Output bytes", "Proof bytes"
+            "Instance type + job name + job size + job params", "Setup duration",
+            "Proof duration", "Verify duration", "Output bytes", "Proof bytes"
         ])
+        systems = []
         for filename in os.listdir(os.path.join(os.getcwd(), "results")):   
             with open("results/" + filename, 'r') as read_file:
                 lines = read_file.readlines()
@@ -25,18 +26,65 @@ def main():
                 while i < len(lines):
                     if lines[i].startswith("+ job_name"):
                          writer.writerow(getCsvRow(filename[:-4], i, lines))
+                    if lines[i].startswith("* prover"):
+                        system = getProverInfo(i, lines)
+                        if system not in systems:
+                            systems.append(system)
                     i += 1
 
+    with open("systems.md", 'w') as write_file:
+        write_file.write(getSystemsTable(systems))
+
 
 def getCsvRow(instance_type, index, file_lines):
-    job_name = file_lines[index].split(':')[1].strip().strip('"')
-    job_size = file_lines[index + 1].split(':')[1].strip()
-    proof_duration = file_lines[index + 2].split(':')[1].strip()
-    verify_duration = file_lines[index + 3].split(':')[1].strip()
-    output_bytes = file_lines[index + 4].split(':')[1].strip()
-    proof_bytes = file_lines[index + 5].split(':')[1].strip()
+    fields = getJobFields(index, file_lines)
+    job_name = fields["job_name"].strip('"')
+    job_size = fields["job_size"]
+    # Logs from before setup timing was added do not have this field
+    setup_duration = fields.get("setup_duration", "")
+    proof_duration = fields["proof_duration"]
+    verify_duration = fields["verify_duration"]
+    output_bytes = fields["output_bytes"]
+    proof_bytes = fields["proof_bytes"]
     first_col = "{} {} {}".format(instance_type, job_name, job_size)
-    return [first_col, proof_duration, verify_duration, output_bytes, proof_bytes]
+    # Jobs that share a name and size are told apart by their parameters
+    job_params = fields.get("job_params", "").strip('"')
+    if job_params:
+        first_col += " " + job_params
+    return [first_col, setup_duration, proof_duration, verify_duration,
+            output_bytes, proof_bytes]
+
+
+def getProverInfo(index, file_lines):
+    fields = {}
+    while index < len(file_lines) and file_lines[index].startswith("* "):
+        key, _, value = file_lines[index][2:].partition(':')
+        fields[key.strip()] = value.strip().strip('"')
+        index += 1
+    return fields
+
+
+def getSystemsTable(systems):
+    rows = [
+        "| System | ZKP System | Field | Commitment hash | Security level | Post-quantum | Version |",
+        "| ------ | :--------: | :---: | :-------------: | :------------: | :----------: | :-----: |",
+    ]
+    for system in systems:
+        rows.append("| [{}]({}) | {} | {} | {} | {} bits | {} | {} |".format(
+            system["name"], system["repository"], system["proof_system"],
+            system["field"], system["commitment_hash"], system["security_bits"],
+            "yes" if system["post_quantum"] == "true" else "no",
+            system["crate_version"]))
+    return "\n".join(rows) + "\n"
+
+
+def getJobFields(index, file_lines):
+    fields = {}
+    while index < len(file_lines) and not file_lines[index].startswith("+ end"):
+        key, _, value = file_lines[index][2:].partition(':')
+        fields[key.strip()] = value.strip()
+        index += 1
+    return fields
 
 
 if __name__ == "__main__":
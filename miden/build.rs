@@ -0,0 +1,23 @@
+//! Exposes the locked miden-vm version to `MidenInfo`, so the systems table
+//! cannot fall behind a dependency bump.
+
+use std::fs;
+
+fn main() {
+    println!("cargo:rerun-if-changed=Cargo.lock");
+
+    let lock = fs::read_to_string("Cargo.lock").expect("Cargo.lock");
+    let version = locked_version(&lock, "miden-vm").expect("miden-vm is not locked");
+    println!("cargo:rustc-env=MIDEN_VERSION={version}");
+}
+
+/// Returns the version of `package` recorded in the lockfile `lock`.
+fn locked_version<'a>(lock: &'a str, package: &str) -> Option<&'a str> {
+    let name = format!("name = \"{package}\"");
+    let mut lines = lock.lines();
+    lines.find(|line| *line == name)?;
+    lines
+        .next()?
+        .strip_prefix("version = \"")?
+        .strip_suffix('"')
+}
@@ -0,0 +1,198 @@
+use miden::{
+    math::{Felt, StarkField},
+    AdviceInputs, Assembler, Kernel, MemAdviceProvider, Program, ProgramInfo, ProofOptions,
+    StackInputs, StackOutputs,
+};
+use miden_core::chiplets;
+use rand::{rngs::StdRng, RngCore, SeedableRng};
+use rustbench::Benchmark;
+
+use super::{stark_proof_breakdown, with_proof_options, JobSpec};
+use crate::proof_options::describe;
+
+/// Hashes a large random buffer with Rescue Prime, the counterpart of the
+/// RISC Zero `big_sha2` job. The input is packed into u32 field elements and
+/// streamed into the VM through the advice stack, eight elements at a time.
+pub struct Job {
+    spec: JobSpec<Vec<u8>>,
+    program: Program,
+    program_info: ProgramInfo,
+    program_inputs: StackInputs,
+    advice_inputs: Vec<u64>,
+    proof_options: ProofOptions,
+    program_outputs: StackOutputs,
+}
+
+/// Same seeded inputs as the RISC Zero `big_sha2::new_jobs`.
+pub fn new_jobs(proof_options: &[ProofOptions]) -> Vec<<Job as Benchmark>::Spec> {
+    let mut rand = StdRng::seed_from_u64(1337);
+    let mut inputs = Vec::new();
+    for job_size in [1024, 2048, 4096, 8192] {
+        let mut input = vec![0; job_size];
+        for byte in input.iter_mut() {
+            *byte = rand.next_u32() as u8;
+        }
+
+        inputs.push(input);
+    }
+    with_proof_options(inputs, proof_options)
+}
+
+/// Packs bytes into field elements, four little-endian bytes per element.
+fn to_elements(bytes: &[u8]) -> Vec<u64> {
+    bytes
+        .chunks(4)
+        .map(|chunk| {
+            let mut word = [0u8; 4];
+            word[..chunk.len()].copy_from_slice(chunk);
+            u32::from_le_bytes(word) as u64
+        })
+        .collect()
+}
+
+impl Benchmark for Job {
+    const NAME: &'static str = "big_rpo";
+    type Spec = JobSpec<Vec<u8>>;
+    type ComputeOut = Vec<u64>;
+    type ProofType = miden::ExecutionProof;
+
+    fn job_size(spec: &Self::Spec) -> u32 {
+        spec.params.len() as u32
+    }
+
+    fn output_size_bytes(output: &Self::ComputeOut, _proof: &Self::ProofType) -> u32 {
+        (output.len() * 8) as u32
+    }
+
+    fn proof_size_bytes(proof: &Self::ProofType) -> u32 {
+        proof.to_bytes().len() as u32
+    }
+
+    fn proof_size_breakdown(proof: &Self::ProofType) -> Vec<(&'static str, u32)> {
+        stark_proof_breakdown(proof)
+    }
+
+    fn new(spec: Self::Spec) -> Self {
+        // Like Rpo256::hash_elements, we pad the input with a one followed by zeros
+        // to a multiple of the rate (8 elements) and flag the padding in the capacity.
+        let mut advice_inputs = to_elements(&spec.params);
+        let padded = !advice_inputs.len().is_multiple_of(8);
+        if padded {
+            advice_inputs.push(1);
+            advice_inputs.resize(advice_inputs.len().next_multiple_of(8), 0);
+        }
+        let num_blocks = advice_inputs.len() / 8;
+
+        let source = format!(
+            "
+            # stack start: [R1, R0, C, addr=0, ...]
+            begin
+                # adv_pipe overwrites the rate with two words from the advice
+                # stack, copies them to memory at addr and applies hperm
+                repeat.{}
+                    adv_pipe
+                end
+
+                # the digest is the first word of the rate
+                dropw
+            end",
+            num_blocks
+        );
+
+        // The capacity is the deepest of the top three words
+        let mut stack_init = vec![0u64; 12];
+        stack_init[0] = padded as u64;
+        let program_inputs = StackInputs::try_from_values(stack_init)
+            .map_err(|e| e.to_string())
+            .unwrap();
+
+        // Compiling the program
+        let assembler = Assembler::default();
+        let program = assembler
+            .compile(source.as_str())
+            .expect("Could not compile source");
+
+        let program_hash = program.hash();
+        let kernel = Kernel::default();
+        let program_info = ProgramInfo::new(program_hash, kernel);
+
+        let proof_options = spec.proof_options.clone();
+
+        let program_outputs = StackOutputs::new(vec![], vec![]);
+
+        Job {
+            spec,
+            program,
+            program_info,
+            program_inputs,
+            advice_inputs,
+            proof_options,
+            program_outputs,
+        }
+    }
+
+    fn spec(&self) -> &Self::Spec {
+        &self.spec
+    }
+
+    fn proof_options(&self) -> String {
+        describe(&self.proof_options)
+    }
+
+    fn security_bits(proof: &Self::ProofType) -> Option<u32> {
+        Some(proof.security_level())
+    }
+
+    /// Compute on VM
+    fn guest_compute(&mut self) -> (Self::ComputeOut, Self::ProofType) {
+        let program = &self.program;
+        let program_input = self.program_inputs.clone();
+        let proof_options = self.proof_options.clone();
+
+        // The input is streamed from the advice stack
+        let advice_inputs = AdviceInputs::default()
+            .with_stack_values(self.advice_inputs.clone())
+            .map_err(|e| e.to_string());
+        let advice_provider = MemAdviceProvider::from(advice_inputs.unwrap());
+
+        let (output, proof) =
+            miden::prove(program, program_input, advice_provider, proof_options).expect("results");
+
+        let mut stack_output = output.stack_truncated(4).to_vec();
+        stack_output.reverse();
+
+        self.program_outputs = output;
+
+        (stack_output, proof)
+    }
+
+    /// Compute on host CPU
+    fn host_compute(&mut self) -> Option<Self::ComputeOut> {
+        let input = to_elements(&self.spec.params)
+            .into_iter()
+            .map(Felt::new)
+            .collect::<Vec<Felt>>();
+
+        let output = chiplets::hasher::hash_elements(&input);
+
+        Some(output.as_elements().iter().map(|x| x.as_int()).collect())
+    }
+
+    fn verify_proof(&self, _output: &Self::ComputeOut, proof: &Self::ProofType) -> bool {
+        let program_info = self.program_info.clone();
+        let program_inputs = self.program_inputs.clone();
+        let program_outputs = self.program_outputs.clone();
+        let stark_proof = proof.clone();
+
+        let result = miden::verify(program_info, program_inputs, program_outputs, stark_proof)
+            .map_err(|err| format!("Program failed verification! - {}", err));
+
+        match result {
+            Ok(_) => true,
+            Err(err) => {
+                println!("{}", err);
+                false
+            }
+        }
+    }
+}
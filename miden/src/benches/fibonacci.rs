@@ -0,0 +1,159 @@
+use miden::{
+    math::{Felt, FieldElement, StarkField},
+    AdviceInputs, Assembler, Kernel, MemAdviceProvider, Program, ProgramInfo, ProofOptions,
+    StackInputs, StackOutputs,
+};
+use rustbench::Benchmark;
+
+use super::{stark_proof_breakdown, with_proof_options, JobSpec};
+use crate::proof_options::describe;
+
+/// Computes the n-th Fibonacci number in the base field with a plain loop of
+/// stack operations, as a baseline without any chiplet or precompile.
+pub struct Job {
+    spec: JobSpec<u32>,
+    program: Program,
+    program_info: ProgramInfo,
+    program_inputs: StackInputs,
+    proof_options: ProofOptions,
+    program_outputs: StackOutputs,
+}
+
+pub fn new_jobs(proof_options: &[ProofOptions]) -> Vec<<Job as Benchmark>::Spec> {
+    with_proof_options(vec![1000, 10_000, 50_000], proof_options)
+}
+
+impl Benchmark for Job {
+    const NAME: &'static str = "fibonacci";
+    type Spec = JobSpec<u32>;
+    type ComputeOut = Vec<u64>;
+    type ProofType = miden::ExecutionProof;
+
+    fn job_size(spec: &Self::Spec) -> u32 {
+        spec.params
+    }
+
+    fn output_size_bytes(output: &Self::ComputeOut, _proof: &Self::ProofType) -> u32 {
+        (output.len() * 8) as u32
+    }
+
+    fn proof_size_bytes(proof: &Self::ProofType) -> u32 {
+        proof.to_bytes().len() as u32
+    }
+
+    fn proof_size_breakdown(proof: &Self::ProofType) -> Vec<(&'static str, u32)> {
+        stark_proof_breakdown(proof)
+    }
+
+    fn new(spec: Self::Spec) -> Self {
+        let num_iter = spec.params;
+        // A while loop keeps the program size constant, unlike repeat which
+        // is unrolled at compile time.
+        let source = "
+            # stack start: [n, b=1, a=0, ...]
+            begin
+                dup neq.0
+                while.true
+                    # [n, b, a, ...] -> [b, a, n, ...]
+                    movdn.2
+                    # [b, a] -> [a + b, b]
+                    swap dup.1 add
+                    # decrement the counter
+                    movup.2 sub.1
+                    dup neq.0
+                end
+                drop
+            end";
+
+        // The last value ends up on top of the stack
+        let input = vec![0, 1, num_iter as u64];
+        let program_inputs = StackInputs::try_from_values(input)
+            .map_err(|e| e.to_string())
+            .unwrap();
+
+        // Compiling the program
+        let assembler = Assembler::default();
+        let program = assembler.compile(source).expect("Could not compile source");
+
+        let program_hash = program.hash();
+        let kernel = Kernel::default();
+        let program_info = ProgramInfo::new(program_hash, kernel);
+
+        let proof_options = spec.proof_options.clone();
+
+        let program_outputs = StackOutputs::new(vec![], vec![]);
+
+        Job {
+            spec,
+            program,
+            program_info,
+            program_inputs,
+            proof_options,
+            program_outputs,
+        }
+    }
+
+    fn spec(&self) -> &Self::Spec {
+        &self.spec
+    }
+
+    fn proof_options(&self) -> String {
+        describe(&self.proof_options)
+    }
+
+    fn security_bits(proof: &Self::ProofType) -> Option<u32> {
+        Some(proof.security_level())
+    }
+
+    /// Compute on VM
+    fn guest_compute(&mut self) -> (Self::ComputeOut, Self::ProofType) {
+        let program = &self.program;
+        let program_input = self.program_inputs.clone();
+        let proof_options = self.proof_options.clone();
+
+        // Creating an empty advice provider
+        let advice_inputs = AdviceInputs::default()
+            .with_stack_values(vec![])
+            .map_err(|e| e.to_string());
+        let advice_provider = MemAdviceProvider::from(advice_inputs.unwrap());
+
+        let (output, proof) =
+            miden::prove(program, program_input, advice_provider, proof_options).expect("results");
+
+        let stack_output = output.stack_truncated(1).to_vec();
+
+        self.program_outputs = output;
+
+        (stack_output, proof)
+    }
+
+    /// Compute on host CPU
+    fn host_compute(&mut self) -> Option<Self::ComputeOut> {
+        let mut a = Felt::ZERO;
+        let mut b = Felt::ONE;
+
+        for _ in 0..self.spec.params {
+            (a, b) = (b, a + b);
+        }
+
+        Some(vec![b.as_int()])
+    }
+
+    fn verify_proof(&self, _output: &Self::ComputeOut, proof: &Self::ProofType) -> bool {
+        let program_info = self.program_info.clone();
+        let program_inputs = self.program_inputs.clone();
+        let program_outputs = self.program_outputs.clone();
+        let stark_proof = proof.clone();
+
+        let result = miden::verify(program_info, program_inputs, program_outputs, stark_proof)
+            .map_err(|err| format!("Program failed verification! - {}", err));
+
+        match result {
+            Ok(_) => true,
+            Err(err) => {
+                println!("{}", err);
+                false
+            }
+        }
+    }
+}
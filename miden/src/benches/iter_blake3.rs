@@ -6,8 +6,11 @@ use miden_crypto::hash::blake::Blake3_256;
 use miden_stdlib::StdLibrary;
 use rustbench::Benchmark;
 
+use super::{stark_proof_breakdown, with_proof_options, JobSpec};
+use crate::proof_options::describe;
+
 pub struct Job {
-    num_iter: u32,
+    spec: JobSpec<u32>,
     program: Program,
     program_info: ProgramInfo,
     program_inputs: StackInputs,
@@ -15,18 +18,18 @@ pub struct Job {
     program_outputs: StackOutputs,
 }
 
-pub fn new_jobs() -> Vec<<Job as Benchmark>::Spec> {
-    vec![10, 100]
+pub fn new_jobs(proof_options: &[ProofOptions]) -> Vec<<Job as Benchmark>::Spec> {
+    with_proof_options(vec![10, 100], proof_options)
 }
 
 impl Benchmark for Job {
     const NAME: &'static str = "iter_blake3";
-    type Spec = u32;
+    type Spec = JobSpec<u32>;
     type ComputeOut = Vec<u64>;
     type ProofType = miden::ExecutionProof;
 
     fn job_size(spec: &Self::Spec) -> u32 {
-        *spec
+        spec.params
     }
 
     fn output_size_bytes(output: &Self::ComputeOut, _proof: &Self::ProofType) -> u32 {
@@ -37,7 +40,12 @@ impl Benchmark for Job {
         proof.to_bytes().len() as u32
     }
 
-    fn new(num_iter: Self::Spec) -> Self {
+    fn proof_size_breakdown(proof: &Self::ProofType) -> Vec<(&'static str, u32)> {
+        stark_proof_breakdown(proof)
+    }
+
+    fn new(spec: Self::Spec) -> Self {
+        let num_iter = spec.params;
         let source = format!(
             "  
             use.std::crypto::hashes::blake3
@@ -70,13 +78,12 @@ impl Benchmark for Job {
         let kernel = Kernel::default();
         let program_info = ProgramInfo::new(program_hash, kernel);
 
-        // default (96 bits of security)
-        let proof_options = ProofOptions::with_96_bit_security();
+        let proof_options = spec.proof_options.clone();
 
         let program_outputs = StackOutputs::new(vec![], vec![]);
 
         Job {
-            num_iter,
+            spec,
             program,
             program_info,
             program_inputs,
@@ -86,7 +93,15 @@ impl Benchmark for Job {
     }
 
     fn spec(&self) -> &Self::Spec {
-        &self.num_iter
+        &self.spec
+    }
+
+    fn proof_options(&self) -> String {
+        describe(&self.proof_options)
+    }
+
+    fn security_bits(proof: &Self::ProofType) -> Option<u32> {
+        Some(proof.security_level())
     }
 
     fn guest_compute(&mut self) -> (Self::ComputeOut, Self::ProofType) {
@@ -114,7 +129,7 @@ impl Benchmark for Job {
         let input = [0u8; 32];
         let mut output = input;
 
-        for _ in 0..self.num_iter {
+        for _ in 0..self.spec.params {
             let pre_output = Blake3_256::hash(&output);
 
             output = pre_output.into();
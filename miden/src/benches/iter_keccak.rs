@@ -0,0 +1,175 @@
+use miden::{
+    AdviceInputs, Assembler, Kernel, MemAdviceProvider, Program, ProgramInfo, ProofOptions,
+    StackInputs, StackOutputs,
+};
+use miden_stdlib::StdLibrary;
+use rustbench::Benchmark;
+use sha3::{Digest, Keccak256};
+
+use super::{stark_proof_breakdown, with_proof_options, JobSpec};
+use crate::proof_options::describe;
+
+/// Iterated Keccak-256. The stdlib only exposes a 64-byte to 32-byte Keccak-256, so every
+/// step hashes the previous digest concatenated with itself, starting from 32 zero bytes.
+pub struct Job {
+    spec: JobSpec<u32>,
+    program: Program,
+    program_info: ProgramInfo,
+    program_inputs: StackInputs,
+    proof_options: ProofOptions,
+    program_outputs: StackOutputs,
+}
+
+pub fn new_jobs(proof_options: &[ProofOptions]) -> Vec<<Job as Benchmark>::Spec> {
+    with_proof_options(vec![1, 2, 5], proof_options)
+}
+
+impl Benchmark for Job {
+    const NAME: &'static str = "iter_keccak";
+    type Spec = JobSpec<u32>;
+    type ComputeOut = Vec<u64>;
+    type ProofType = miden::ExecutionProof;
+
+    fn job_size(spec: &Self::Spec) -> u32 {
+        spec.params
+    }
+
+    fn output_size_bytes(output: &Self::ComputeOut, _proof: &Self::ProofType) -> u32 {
+        (output.len() * 8) as u32
+    }
+
+    fn proof_size_bytes(proof: &Self::ProofType) -> u32 {
+        proof.to_bytes().len() as u32
+    }
+
+    fn proof_size_breakdown(proof: &Self::ProofType) -> Vec<(&'static str, u32)> {
+        stark_proof_breakdown(proof)
+    }
+
+    fn new(spec: Self::Spec) -> Self {
+        let num_iter = spec.params;
+        // The digest is kept on the stack as eight u32 limbs and duplicated to form the next
+        // 64-byte input.
+        let source = format!(
+            "
+            use.std::crypto::hashes::keccak256
+
+            begin
+                repeat.{}
+                    dupw.1
+                    dupw.1
+                    exec.keccak256::hash
+                    # drop the previous digest
+                    swapdw dropw dropw
+                end
+            end",
+            num_iter
+        );
+
+        let input = vec![0u64; 8];
+        let program_inputs = StackInputs::try_from_values(input)
+            .map_err(|e| e.to_string())
+            .unwrap();
+
+        // compiling the program
+        let assembler = Assembler::default()
+            .with_library(&StdLibrary::default())
+            .expect("failed to load stdlib");
+
+        let program = assembler
+            .compile(source.as_str())
+            .expect("Could not compile source");
+        let program_hash = program.hash();
+        let kernel = Kernel::default();
+        let program_info = ProgramInfo::new(program_hash, kernel);
+
+        let proof_options = spec.proof_options.clone();
+
+        let program_outputs = StackOutputs::new(vec![], vec![]);
+
+        Job {
+            spec,
+            program,
+            program_info,
+            program_inputs,
+            proof_options,
+            program_outputs,
+        }
+    }
+
+    fn spec(&self) -> &Self::Spec {
+        &self.spec
+    }
+
+    fn proof_options(&self) -> String {
+        describe(&self.proof_options)
+    }
+
+    fn security_bits(proof: &Self::ProofType) -> Option<u32> {
+        Some(proof.security_level())
+    }
+
+    fn guest_compute(&mut self) -> (Self::ComputeOut, Self::ProofType) {
+        let program = self.program.clone();
+        let program_inputs = self.program_inputs.clone();
+        let proof_options = self.proof_options.clone();
+
+        // Creating an empty advice provider
+        let advice_inputs = AdviceInputs::default()
+            .with_stack_values(vec![])
+            .map_err(|e| e.to_string());
+        let advice_provider = MemAdviceProvider::from(advice_inputs.unwrap());
+
+        let (output, proof) =
+            miden::prove(&program, program_inputs, advice_provider, proof_options)
+                .expect("results");
+
+        let stack_output = output.stack_truncated(8).to_vec();
+
+        self.program_outputs = output;
+
+        (stack_output, proof)
+    }
+
+    fn host_compute(&mut self) -> Option<<Self as Benchmark>::ComputeOut> {
+        let mut data = Vec::from([0u8; 32]);
+
+        for _i in 0..self.spec.params {
+            let mut hasher = Keccak256::new();
+            hasher.update(&data);
+            hasher.update(&data);
+            data = hasher.finalize().to_vec();
+        }
+
+        // The stdlib represents the digest as four little endian u64 values, each split into
+        // its high and low 32 bits.
+        let mut h_output = Vec::<u64>::new();
+
+        for chunk in data.chunks(8) {
+            let limb = u64::from_le_bytes(chunk.try_into().unwrap());
+            h_output.push(limb >> 32);
+            h_output.push(limb & 0xffff_ffff);
+        }
+
+        Some(h_output)
+    }
+
+    fn verify_proof(&self, _output: &Self::ComputeOut, proof: &Self::ProofType) -> bool {
+        let program_info = self.program_info.clone();
+        let program_inputs = self.program_inputs.clone();
+        let program_outputs = self.program_outputs.clone();
+
+        let stark_proof = proof.clone();
+
+        let result = miden::verify(program_info, program_inputs, program_outputs, stark_proof)
+            .map_err(|err| format!("Program failed verification! - {}", err));
+
+        match result {
+            Ok(_) => true,
+            Err(err) => {
+                println!("{}", err);
+                false
+            }
+        }
+    }
+}
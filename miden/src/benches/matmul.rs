@@ -0,0 +1,250 @@
+use miden::{
+    math::{Felt, StarkField},
+    AdviceInputs, Assembler, Kernel, MemAdviceProvider, Program, ProgramInfo, ProofOptions,
+    StackInputs, StackOutputs,
+};
+use miden_core::chiplets;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use rustbench::Benchmark;
+
+use super::{stark_proof_breakdown, with_proof_options, JobSpec};
+use crate::proof_options::describe;
+
+/// Multiplies two random n×n matrices with entries below 256 and outputs the
+/// Rescue Prime hash of the product. The job size is n³, the number of
+/// multiply-adds, so proof cost can be read against it directly.
+pub struct Job {
+    spec: JobSpec<u32>,
+    program: Program,
+    program_info: ProgramInfo,
+    program_inputs: StackInputs,
+    advice_inputs: Vec<u64>,
+    proof_options: ProofOptions,
+    program_outputs: StackOutputs,
+    a: Vec<u64>,
+    b: Vec<u64>,
+}
+
+pub fn new_jobs(proof_options: &[ProofOptions]) -> Vec<<Job as Benchmark>::Spec> {
+    with_proof_options(vec![4, 8, 16, 32], proof_options)
+}
+
+/// Two random n×n matrices in row-major order, the same as in the RISC Zero job.
+pub fn random_matrices(n: usize) -> (Vec<u64>, Vec<u64>) {
+    let mut rand = StdRng::seed_from_u64(1337);
+    let a = (0..n * n).map(|_| rand.gen_range(0..256)).collect();
+    let b = (0..n * n).map(|_| rand.gen_range(0..256)).collect();
+    (a, b)
+}
+
+impl Benchmark for Job {
+    const NAME: &'static str = "matmul";
+    type Spec = JobSpec<u32>;
+    type ComputeOut = Vec<u64>;
+    type ProofType = miden::ExecutionProof;
+
+    fn job_size(spec: &Self::Spec) -> u32 {
+        spec.params.pow(3)
+    }
+
+    fn output_size_bytes(output: &Self::ComputeOut, _proof: &Self::ProofType) -> u32 {
+        (output.len() * 8) as u32
+    }
+
+    fn proof_size_bytes(proof: &Self::ProofType) -> u32 {
+        proof.to_bytes().len() as u32
+    }
+
+    fn proof_size_breakdown(proof: &Self::ProofType) -> Vec<(&'static str, u32)> {
+        stark_proof_breakdown(proof)
+    }
+
+    fn new(spec: Self::Spec) -> Self {
+        let n = spec.params as usize;
+        // Pushes the next 8 elements of C; the pointer sits below those
+        // already pushed and the capacity.
+        let load_block = (0..8)
+            .map(|m| format!("dup.{} add.{} mem_load", 4 + m, m))
+            .collect::<Vec<String>>()
+            .join(" ");
+        // Memory holds one element per address: A at [0, n²), B at [n², 2n²)
+        // and the product C at [2n², 3n²), all in row-major order.
+        // n² is a multiple of 8, so C fills the rate of the hasher exactly.
+        let source = format!(
+            "
+            begin
+                # read A and B from the advice stack into memory
+                push.{nn2} push.0
+                # [addr, count, ...]
+                dup.1 neq.0
+                while.true
+                    adv_push.1 dup.1 mem_store
+                    add.1 swap sub.1 swap
+                    dup.1 neq.0
+                end
+                drop drop
+
+                # C[i][j] = sum_k A[i][k] * B[k][j]
+                push.0
+                push.1
+                while.true
+                    # [i, ...]
+                    push.0
+                    push.1
+                    while.true
+                        # [j, i, ...]
+                        # pointers to A[i][0] and B[0][j]
+                        dup.1 mul.{n}
+                        dup.1 add.{nn}
+                        push.0
+                        # [acc, pb, pa, j, i, ...]
+                        repeat.{n}
+                            dup.2 mem_load
+                            dup.2 mem_load
+                            mul add
+                            # advance to A[i][k+1] and B[k+1][j]
+                            swap add.{n} swap
+                            movup.2 add.1 movdn.2
+                        end
+                        swap drop swap drop
+                        # [acc, j, i, ...]
+                        dup.2 mul.{n} dup.2 add add.{nn2}
+                        mem_store
+                        # [j, i, ...]
+                        add.1 dup neq.{n}
+                    end
+                    drop add.1 dup neq.{n}
+                end
+                drop
+
+                # hash C, absorbing 8 elements per permutation
+                push.{nn2}
+                padw padw padw
+                # [rate, rate, capacity, ptr, ...]
+                repeat.{blocks}
+                    dropw dropw
+                    {load_block}
+                    # [e_7, .., e_0, capacity, ptr, ...]
+                    hperm
+                    movup.12 add.8 movdn.12
+                end
+
+                # the digest is the first half of the rate
+                dropw swapw dropw movup.4 drop
+            end",
+            n = n,
+            nn = n * n,
+            nn2 = 2 * n * n,
+            blocks = n * n / 8,
+            load_block = load_block,
+        );
+
+        let (a, b) = random_matrices(n);
+        let mut advice_inputs = a.clone();
+        advice_inputs.extend(b.iter());
+
+        let program_inputs = StackInputs::try_from_values(vec![])
+            .map_err(|e| e.to_string())
+            .unwrap();
+
+        // Compiling the program
+        let assembler = Assembler::default();
+        let program = assembler
+            .compile(source.as_str())
+            .expect("Could not compile source");
+
+        let program_hash = program.hash();
+        let kernel = Kernel::default();
+        let program_info = ProgramInfo::new(program_hash, kernel);
+
+        let proof_options = spec.proof_options.clone();
+
+        let program_outputs = StackOutputs::new(vec![], vec![]);
+
+        Job {
+            spec,
+            program,
+            program_info,
+            program_inputs,
+            advice_inputs,
+            proof_options,
+            program_outputs,
+            a,
+            b,
+        }
+    }
+
+    fn spec(&self) -> &Self::Spec {
+        &self.spec
+    }
+
+    fn job_params(&self) -> String {
+        format!("n={}", self.spec.params)
+    }
+
+    fn proof_options(&self) -> String {
+        describe(&self.proof_options)
+    }
+
+    fn security_bits(proof: &Self::ProofType) -> Option<u32> {
+        Some(proof.security_level())
+    }
+
+    /// Compute on VM
+    fn guest_compute(&mut self) -> (Self::ComputeOut, Self::ProofType) {
+        let program = &self.program;
+        let program_input = self.program_inputs.clone();
+        let proof_options = self.proof_options.clone();
+
+        // The matrices are read from the advice stack, A first
+        let advice_inputs = AdviceInputs::default()
+            .with_stack_values(self.advice_inputs.clone())
+            .map_err(|e| e.to_string());
+        let advice_provider = MemAdviceProvider::from(advice_inputs.unwrap());
+
+        let (output, proof) =
+            miden::prove(program, program_input, advice_provider, proof_options).expect("results");
+
+        let mut stack_output = output.stack_truncated(4).to_vec();
+        stack_output.reverse();
+
+        self.program_outputs = output;
+
+        (stack_output, proof)
+    }
+
+    /// Compute on host CPU
+    fn host_compute(&mut self) -> Option<Self::ComputeOut> {
+        let n = self.spec.params as usize;
+
+        let mut c = Vec::with_capacity(n * n);
+        for i in 0..n {
+            for j in 0..n {
+                let sum: u64 = (0..n).map(|k| self.a[i * n + k] * self.b[k * n + j]).sum();
+                c.push(Felt::new(sum));
+            }
+        }
+
+        let digest = chiplets::hasher::hash_elements(&c);
+
+        Some(digest.as_elements().iter().map(|x| x.as_int()).collect())
+    }
+
+    fn verify_proof(&self, _output: &Self::ComputeOut, proof: &Self::ProofType) -> bool {
+        let program_info = self.program_info.clone();
+        let program_inputs = self.program_inputs.clone();
+        let program_outputs = self.program_outputs.clone();
+        let stark_proof = proof.clone();
+
+        let result = miden::verify(program_info, program_inputs, program_outputs, stark_proof)
+            .map_err(|err| format!("Program failed verification! - {}", err));
+
+        match result {
+            Ok(_) => true,
+            Err(err) => {
+                println!("{}", err);
+                false
+            }
+        }
+    }
+}
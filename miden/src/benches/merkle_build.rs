@@ -0,0 +1,212 @@
+use miden::{
+    math::{Felt, StarkField},
+    AdviceInputs, Assembler, Kernel, MemAdviceProvider, Program, ProgramInfo, ProofOptions,
+    StackInputs, StackOutputs, Word,
+};
+use miden_core::crypto::merkle::MerkleTree;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use rustbench::Benchmark;
+
+use super::{stark_proof_breakdown, with_proof_options, JobSpec};
+use crate::proof_options::describe;
+
+/// Computes the root of a dense binary Merkle tree over `spec` random leaves
+/// with `hmerge`. The job size is the number of hashed nodes, n - 1.
+pub struct Job {
+    spec: JobSpec<u32>,
+    program: Program,
+    program_info: ProgramInfo,
+    program_inputs: StackInputs,
+    proof_options: ProofOptions,
+    program_outputs: StackOutputs,
+    leaves: Vec<Word>,
+}
+
+pub fn new_jobs(proof_options: &[ProofOptions]) -> Vec<<Job as Benchmark>::Spec> {
+    with_proof_options(vec![1 << 8, 1 << 10, 1 << 12], proof_options)
+}
+
+fn random_leaves(n: usize) -> Vec<Word> {
+    let mut rand = StdRng::seed_from_u64(1337);
+    (0..n)
+        .map(|_| [(); 4].map(|_| Felt::from(rand.gen::<u32>())))
+        .collect()
+}
+
+/// Hashes the `m` nodes at addresses [0, m) in pairs and stores the m / 2
+/// parents at [0, m / 2). A parent never overwrites a node still to be read.
+fn merge_level(m: u32) -> String {
+    format!(
+        "
+        push.0
+        push.1
+        while.true
+            # [i, ...] -> [2i, 2i + 1, i, ...]
+            dup mul.2 dup add.1 swap
+            padw movup.4 mem_loadw
+            padw movup.8 mem_loadw
+            # [R, L, i, ...]
+            hmerge
+            dup.4 mem_storew dropw
+            add.1 dup neq.{half}
+        end
+        drop
+        ",
+        half = m / 2
+    )
+}
+
+impl Benchmark for Job {
+    const NAME: &'static str = "merkle_build";
+    type Spec = JobSpec<u32>;
+    type ComputeOut = Vec<u64>;
+    type ProofType = miden::ExecutionProof;
+
+    fn job_size(spec: &Self::Spec) -> u32 {
+        spec.params - 1
+    }
+
+    fn output_size_bytes(output: &Self::ComputeOut, _proof: &Self::ProofType) -> u32 {
+        (output.len() * 8) as u32
+    }
+
+    fn proof_size_bytes(proof: &Self::ProofType) -> u32 {
+        proof.to_bytes().len() as u32
+    }
+
+    fn proof_size_breakdown(proof: &Self::ProofType) -> Vec<(&'static str, u32)> {
+        stark_proof_breakdown(proof)
+    }
+
+    fn new(spec: Self::Spec) -> Self {
+        let n = spec.params;
+        assert!(n.is_power_of_two() && n > 1, "n must be a power of two");
+
+        // Memory holds one word per address, the leaves start at [0, n)
+        let levels = (1..=n.trailing_zeros())
+            .map(|level| merge_level(n >> (level - 1)))
+            .collect::<Vec<String>>()
+            .join("\n");
+        let source = format!(
+            "
+            begin
+                # read the leaves from the advice stack into memory
+                push.{n} push.0
+                # [addr, count, ...]
+                dup.1 neq.0
+                while.true
+                    padw adv_loadw dup.4 mem_storew dropw
+                    add.1 swap sub.1 swap
+                    dup.1 neq.0
+                end
+                drop drop
+
+                {levels}
+
+                # the root ends up at address 0
+                padw push.0 mem_loadw
+            end",
+            n = n,
+            levels = levels,
+        );
+
+        let leaves = random_leaves(n as usize);
+
+        let program_inputs = StackInputs::try_from_values(vec![])
+            .map_err(|e| e.to_string())
+            .unwrap();
+
+        // Compiling the program
+        let assembler = Assembler::default();
+        let program = assembler
+            .compile(source.as_str())
+            .expect("Could not compile source");
+
+        let program_hash = program.hash();
+        let kernel = Kernel::default();
+        let program_info = ProgramInfo::new(program_hash, kernel);
+
+        let proof_options = spec.proof_options.clone();
+
+        let program_outputs = StackOutputs::new(vec![], vec![]);
+
+        Job {
+            spec,
+            program,
+            program_info,
+            program_inputs,
+            proof_options,
+            program_outputs,
+            leaves,
+        }
+    }
+
+    fn spec(&self) -> &Self::Spec {
+        &self.spec
+    }
+
+    fn job_params(&self) -> String {
+        format!("leaves={}", self.spec.params)
+    }
+
+    fn proof_options(&self) -> String {
+        describe(&self.proof_options)
+    }
+
+    fn security_bits(proof: &Self::ProofType) -> Option<u32> {
+        Some(proof.security_level())
+    }
+
+    /// Compute on VM
+    fn guest_compute(&mut self) -> (Self::ComputeOut, Self::ProofType) {
+        let program = &self.program;
+        let program_input = self.program_inputs.clone();
+        let proof_options = self.proof_options.clone();
+
+        // The leaves are read from the advice stack, one word at a time
+        let advice_stack: Vec<u64> = self
+            .leaves
+            .iter()
+            .flat_map(|leaf| leaf.iter().map(|x| x.as_int()))
+            .collect();
+        let advice_inputs = AdviceInputs::default()
+            .with_stack_values(advice_stack)
+            .map_err(|e| e.to_string());
+        let advice_provider = MemAdviceProvider::from(advice_inputs.unwrap());
+
+        let (output, proof) =
+            miden::prove(program, program_input, advice_provider, proof_options).expect("results");
+
+        let mut stack_output = output.stack_truncated(4).to_vec();
+        stack_output.reverse();
+
+        self.program_outputs = output;
+
+        (stack_output, proof)
+    }
+
+    /// Compute on host CPU
+    fn host_compute(&mut self) -> Option<Self::ComputeOut> {
+        let tree = MerkleTree::new(self.leaves.clone()).unwrap();
+
+        Some(tree.root().iter().map(|x| x.as_int()).collect())
+    }
+
+    fn verify_proof(&self, _output: &Self::ComputeOut, proof: &Self::ProofType) -> bool {
+        let program_info = self.program_info.clone();
+        let program_inputs = self.program_inputs.clone();
+        let program_outputs = self.program_outputs.clone();
+        let stark_proof = proof.clone();
+
+        let result = miden::verify(program_info, program_inputs, program_outputs, stark_proof)
+            .map_err(|err| format!("Program failed verification! - {}", err));
+
+        match result {
+            Ok(_) => true,
+            Err(err) => {
+                println!("{}", err);
+                false
+            }
+        }
+    }
+}
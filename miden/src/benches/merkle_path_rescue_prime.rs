@@ -1,41 +1,88 @@
+use std::collections::HashSet;
+
 use miden::{
     crypto::MerkleStore,
     math::{Felt, FieldElement},
     AdviceInputs, Assembler, Kernel, MemAdviceProvider, Program, ProgramInfo, ProofOptions,
     StackInputs, StackOutputs, Word,
 };
+use miden_core::crypto::{
+    hash::Rpo256,
+    merkle::{MerklePath, MerkleTree, NodeIndex, SimpleSmt},
+};
 use miden_core::StarkField;
+use rand::{rngs::StdRng, Rng, SeedableRng};
 use rustbench::Benchmark;
 
-/// Create a Merkle path of depth 32 and then  
-/// the job_size is the number of Merkle paths we verify.
-/// So, for job_size=10 we verify 10 Merkle paths of depth 32.
-/// Unfortunately in Miden v0.5 I can only create a Sparse Merkle Tree of depth 64.
-/// ToDo: Update benchmark when we release Miden v0.6
+use super::{stark_proof_breakdown, with_proof_options, JobSpec};
+use crate::proof_options::describe;
+
+/// How the leaves of the tree are populated.
+#[derive(Clone, Copy)]
+pub enum TreeType {
+    /// Every one of the 2^depth leaves is set.
+    Dense,
+    /// Only `num_leaves` leaves at random indices are set, the rest are empty.
+    Sparse { num_leaves: u64 },
+}
+
+/// Verifies `num_paths` Merkle paths of a tree of the given depth. The opened
+/// leaves are picked at random among the populated leaves of the tree, so for
+/// num_paths=10 and depth=32 we verify 10 Merkle paths of depth 32.
+/// The program outputs the root and a commitment to the opened leaves, which
+/// the host recomputes from paths it verifies with miden_crypto.
+#[derive(Clone, Copy)]
+pub struct Spec {
+    pub num_paths: u32,
+    pub depth: u8,
+    pub tree: TreeType,
+}
 
 pub struct Job {
-    num_iter: u32,
+    spec: JobSpec<Spec>,
     program: Program,
     program_info: ProgramInfo,
     program_inputs: StackInputs,
     advice_provider: MemAdviceProvider,
     proof_options: ProofOptions,
     program_outputs: StackOutputs,
-    root_as_u64: Vec<u64>,
+    root: Word,
+    paths: Vec<(u64, Word, MerklePath)>,
+}
+
+pub fn new_jobs(proof_options: &[ProofOptions]) -> Vec<<Job as Benchmark>::Spec> {
+    let trees = [
+        (16, TreeType::Dense),
+        (20, TreeType::Sparse { num_leaves: 1000 }),
+        (32, TreeType::Sparse { num_leaves: 1000 }),
+    ];
+
+    let mut specs = Vec::new();
+    for (depth, tree) in trees {
+        for num_paths in [10, 100, 1000] {
+            specs.push(Spec {
+                num_paths,
+                depth,
+                tree,
+            });
+        }
+    }
+    with_proof_options(specs, proof_options)
 }
 
-pub fn new_jobs() -> Vec<<Job as Benchmark>::Spec> {
-    vec![10, 100, 1000]
+/// The leaf stored at `index`: [index, 0, 0, 0]
+fn leaf(index: u64) -> Word {
+    [Felt::new(index), Felt::ZERO, Felt::ZERO, Felt::ZERO]
 }
 
 impl Benchmark for Job {
     const NAME: &'static str = "merkle_rescue_prime";
-    type Spec = u32;
+    type Spec = JobSpec<Spec>;
     type ComputeOut = Vec<u64>;
     type ProofType = miden::ExecutionProof;
 
     fn job_size(spec: &Self::Spec) -> u32 {
-        *spec
+        spec.params.num_paths
     }
 
     fn output_size_bytes(output: &Self::ComputeOut, _proof: &Self::ProofType) -> u32 {
@@ -46,52 +93,67 @@ impl Benchmark for Job {
         proof.to_bytes().len() as u32
     }
 
-    fn new(num_iter: Self::Spec) -> Self {
+    fn proof_size_breakdown(proof: &Self::ProofType) -> Vec<(&'static str, u32)> {
+        stark_proof_breakdown(proof)
+    }
+
+    fn new(spec: Self::Spec) -> Self {
+        let Spec {
+            num_paths,
+            depth,
+            tree,
+        } = spec.params;
         let source = format!(
-            "  
+            "
             begin
             # verify a merkle path
                 repeat.{}
-      
-                    # stack = [d, i, R, ...]
-                    # duplicate the index and use as counter
-                    dup.1 push.1 add movdn.6 
-                    # stack = [d, i, R, (i+1) ...]
-        
+
+                    # stack = [R, C, ...]
+                    # read the index of the next leaf from the advice stack
+                    # and keep a copy of it below the root
+                    adv_push.1 dup movdn.5
+                    # stack = [i, R, i, C, ...]
+
                     # verify merkle path for node i
+                    push.{}
                     mtree_get
-                    # [V, R, (i+1) ...]
+                    # [V, R, i, C, ...]
 
                     # now we check if V is what we expect.
-                    # our leafs look like this: [0, 0, 0, counter]
-                    # get the word with the counter copied back
-                    dup.8 push.1 sub 
-                    #[i, V, R, (i+1),  ...]
-                    
+                    # our leafs look like this: [0, 0, 0, i]
+                    dup.8
+                    #[i, V, R, i, C, ...]
+
                     # then make it a Word
                     padw drop
-                    #[0, 0, 0, i, V, R, (i+1),  ...]
-
+                    #[0, 0, 0, i, V, R, i, C, ...]
 
-                    # if the two top words are equal, 
+                    # if the two top words are equal,
                     # [0, 0, 0, i] == V,
                     # we have the expected leaf
                     eqw
 
                     # fails if top stack element is 0
-                    assert 
-
-                    # if it did not fail, we continue with the next iteration
-                    dropw dropw
-                    #[R, (i+1),  ...]
-                    
-                    movup.4
-                    push.63
-                    #[63, (i+1), R,  ...]
+                    assert
+
+                    # if it did not fail, we fold the leaf into the commitment
+                    dropw
+                    movup.8
+                    drop
+                    #[V, R, C, ...]
+
+                    movupw.2
+                    swapw
+                    #[V, C, R, ...]
+
+                    # C = hash(C, V)
+                    hmerge
+                    swapw
+                    #[R, C, ...]
                 end
-                drop drop
             end",
-            num_iter
+            num_paths, depth
         );
 
         // Compiling the program
@@ -103,89 +165,135 @@ impl Benchmark for Job {
         let kernel = Kernel::default();
         let program_info = ProgramInfo::new(program_hash, kernel);
 
-        // Default (96 bits of security)
-        let proof_options = ProofOptions::with_96_bit_security();
+        let proof_options = spec.proof_options.clone();
 
         let program_outputs = StackOutputs::new(vec![], vec![]);
 
-        // We first create an array of keys as basis of our Sparse Merkle Tree.
-        // For max Job size 1000 we need 1000 keys.
-        // We then transform every number into a Word by adding Felt::Zero
-        let merkle_leafs_keys: Vec<u64> = (0..1000).collect();
-        let mut merkle_leafs: Vec<(u64, Word)> = Vec::new();
-
-        for i in 0..merkle_leafs_keys.len() {
-            merkle_leafs.push((
-                i as u64,
-                [
-                    Felt::new(merkle_leafs_keys[i]),
-                    Felt::ZERO,
-                    Felt::ZERO,
-                    Felt::ZERO,
-                ],
-            ));
-        }
-
-        // Now we create a Sparse Merkle Tree with the leafs we just created
+        // We first build the tree and put its nodes into a MerkleStore, from which
+        // mtree_get reads the Merkle paths. Dense trees are added in full, for
+        // sparse trees we add the paths of all populated leaves.
+        // The leaves to open are drawn at random from the populated leaves, and we
+        // keep their paths in the tree for the host to verify.
+        let mut rand = StdRng::seed_from_u64(1337);
         let mut merkle_store = MerkleStore::new();
-        let smt_root = merkle_store.add_sparse_merkle_tree(merkle_leafs).unwrap();
-
-        let advice_set = AdviceInputs::default().with_merkle_store(merkle_store);
+        let (root, paths): (Word, Vec<(u64, Word, MerklePath)>) = match tree {
+            TreeType::Dense => {
+                let leaves: Vec<Word> = (0..1u64 << depth).map(leaf).collect();
+                let tree = MerkleTree::new(leaves.clone()).unwrap();
+                let root = merkle_store.add_merkle_tree(leaves).unwrap();
+                assert_eq!(root, tree.root());
+
+                let paths = (0..num_paths)
+                    .map(|_| {
+                        let index = rand.gen_range(0..1u64 << depth);
+                        let path = tree.get_path(NodeIndex::new(depth, index)).unwrap();
+                        (index, leaf(index), path)
+                    })
+                    .collect();
+                (root, paths)
+            }
+            TreeType::Sparse { num_leaves } => {
+                let mut used = HashSet::new();
+                let mut keys = Vec::new();
+                while (keys.len() as u64) < num_leaves {
+                    let key = rand.gen_range(0..1u64 << depth);
+                    if used.insert(key) {
+                        keys.push(key);
+                    }
+                }
+
+                let smt = SimpleSmt::new(depth)
+                    .unwrap()
+                    .with_leaves(keys.iter().map(|key| (*key, leaf(*key))))
+                    .unwrap();
+                let all_paths = keys
+                    .iter()
+                    .map(|key| (*key, leaf(*key), smt.get_leaf_path(*key).unwrap()));
+                let root = merkle_store.add_merkle_paths(all_paths).unwrap();
+                assert_eq!(root, smt.root());
+
+                let paths = (0..num_paths)
+                    .map(|_| {
+                        let key = keys[rand.gen_range(0..keys.len())];
+                        (key, leaf(key), smt.get_leaf_path(key).unwrap())
+                    })
+                    .collect();
+                (root, paths)
+            }
+        };
+
+        // The indices of the opened leaves are passed to the program through the
+        // advice stack, one index per path.
+        let opened: Vec<u64> = paths.iter().map(|(index, _, _)| *index).collect();
+
+        let advice_set = AdviceInputs::default()
+            .with_stack_values(opened)
+            .unwrap()
+            .with_merkle_store(merkle_store);
 
         let advice_provider = MemAdviceProvider::from(advice_set);
 
-        let root_as_u64 = smt_root.iter().map(|x| x.as_int()).collect::<Vec<u64>>();
-
-        // Per job size we now create as many ProgramInputs as we want to prove.
-        // Per Merkle leaf and Rescue Prime hash we have four u64 values as input
         // mtree_get needs the depth of the tree, the index of the leaf and the root of
-        // the tree as stack_init: [d, i, R, ...]. If true it returns the leaf V together
-        // with the root as output: [V, R, ..]. If false the program fails.
-        // So with 100 merkle paths to verfiy we only need to change the index i 100
-        // times and store V for each path
-
-        // We want as stack_initi [d, i, R, ...], with d = 2 and i = 0 and R = root_as_u64
-        let mut stack_init = Vec::<u64>::new();
-
-        // Depth
-        stack_init.push(63);
-        // Start index
-        stack_init.push(0);
-        // Root
-        for element in root_as_u64.iter().rev() {
-            stack_init.push(*element)
-        }
-        // We reverse the stack_init to get the correct order
-        stack_init.reverse();
-
-        // Finally we create the StackInputs and add the tree as advice_sets
+        // the tree on the stack: [d, i, R, ...]. If the path is valid it returns the
+        // leaf V together with the root: [V, R, ..]. If not the program fails.
+        // Only the root and the empty commitment C are program inputs, the depth is
+        // part of the program and the indices come from the advice stack. The last
+        // value ends up on top, so we get [R, C, ...].
+        let mut stack_init = vec![0u64; 4];
+        stack_init.extend(root.iter().map(|x| x.as_int()));
         let program_inputs = StackInputs::try_from_values(stack_init)
             .map_err(|e| e.to_string())
             .unwrap();
 
         Job {
-            num_iter,
+            spec,
             program,
             program_info,
             program_inputs,
             advice_provider,
             proof_options,
             program_outputs,
-            root_as_u64,
+            root,
+            paths,
         }
     }
 
     fn spec(&self) -> &Self::Spec {
-        &self.num_iter
+        &self.spec
+    }
+
+    fn job_params(&self) -> String {
+        let Spec { depth, tree, .. } = self.spec.params;
+        match tree {
+            TreeType::Dense => format!("tree=dense depth={}", depth),
+            TreeType::Sparse { num_leaves } => {
+                format!("tree=sparse depth={} leaves={}", depth, num_leaves)
+            }
+        }
+    }
+
+    fn proof_options(&self) -> String {
+        describe(&self.proof_options)
+    }
+
+    fn security_bits(proof: &Self::ProofType) -> Option<u32> {
+        Some(proof.security_level())
     }
 
     /// Compute on host CPU
     fn host_compute(&mut self) -> Option<Self::ComputeOut> {
-        // Actually, there is nothing to host_compute.
-        // The assembly program fails, if the merkle path cannot be verified.
-        // So we just return the root of the tree.
+        // Verify every opened path against the root and fold the leaves into
+        // the same commitment the program computes: C = hash(C, V)
+        let mut commitment: Word = [Felt::ZERO; 4];
+        for (index, leaf, path) in self.paths.iter() {
+            assert!(path.verify(*index, *leaf, &self.root));
+            commitment = Rpo256::merge(&[commitment.into(), (*leaf).into()]).into();
+        }
+
+        let mut h_output: Vec<u64> = commitment.iter().map(|x| x.as_int()).collect();
+        h_output.extend(self.root.iter().map(|x| x.as_int()));
 
-        Some(self.root_as_u64.clone())
+        Some(h_output)
     }
 
     /// Compute on VM
@@ -199,7 +307,8 @@ impl Benchmark for Job {
             miden::prove(&program, program_inputs, advice_provider, proof_options)
                 .expect("results");
 
-        let mut stack_output = output.stack_truncated(4).to_vec();
+        // [R, C] with the last element of each word on top, reversed to [C, R]
+        let mut stack_output = output.stack_truncated(8).to_vec();
         stack_output.reverse();
 
         self.program_outputs = output;
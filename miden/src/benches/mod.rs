@@ -1,4 +1,86 @@
+use miden::{ExecutionProof, ProofOptions};
+use miden_core::utils::Serializable;
+
+pub mod big_rpo;
+pub mod fibonacci;
 pub mod iter_blake3;
+pub mod iter_keccak;
 pub mod iter_rescue_prime;
 pub mod iter_sha2;
+pub mod matmul;
+pub mod merkle_build;
 pub mod merkle_path_rescue_prime;
+pub mod random_access;
+pub mod smt_update;
+pub mod sort;
+pub mod sudoku;
+pub mod u256_mulmod;
+
+/// Parameters of a single job together with the proof options it is proven with.
+#[derive(Clone)]
+pub struct JobSpec<T> {
+    pub params: T,
+    pub proof_options: ProofOptions,
+}
+
+/// Creates one job for every combination of `params` and `proof_options`.
+pub fn with_proof_options<T: Clone>(
+    params: Vec<T>,
+    proof_options: &[ProofOptions],
+) -> Vec<JobSpec<T>> {
+    let mut jobs = Vec::new();
+    for options in proof_options {
+        for p in params.iter() {
+            jobs.push(JobSpec {
+                params: p.clone(),
+                proof_options: options.clone(),
+            });
+        }
+    }
+    jobs
+}
+
+/// Splits a serialized execution proof into the parts of the underlying STARK.
+pub fn stark_proof_breakdown(proof: &ExecutionProof) -> Vec<(&'static str, u32)> {
+    let stark_proof = proof.stark_proof();
+
+    let queries = stark_proof
+        .trace_queries
+        .iter()
+        .chain([&stark_proof.constraint_queries])
+        .map(|q| q.to_bytes().len())
+        .sum::<usize>();
+
+    // The commitments are a length prefix followed by one root per trace
+    // segment, one for the constraint evaluations and one per FRI layer plus
+    // the FRI remainder, all of the same size.
+    let commitments = stark_proof.commitments.to_bytes().len() - 2;
+    let num_trace_roots = stark_proof.trace_queries.len();
+    let num_roots = num_trace_roots + 1 + stark_proof.fri_proof.num_layers() + 1;
+    assert_eq!(commitments % num_roots, 0);
+    let root = commitments / num_roots;
+
+    let breakdown = vec![
+        // hash function tag and proof context (trace layout, options)
+        ("context", 1 + stark_proof.context.to_bytes().len()),
+        ("trace_commitments", 2 + num_trace_roots * root),
+        ("constraint_commitment", root),
+        (
+            "fri_commitments",
+            commitments - (num_trace_roots + 1) * root,
+        ),
+        ("ood_frame", stark_proof.ood_frame.to_bytes().len()),
+        ("fri_layers", stark_proof.fri_proof.to_bytes().len()),
+        ("queries", queries),
+        ("pow", stark_proof.pow_nonce.to_le_bytes().len()),
+    ];
+    assert_eq!(
+        breakdown.iter().map(|(_, bytes)| bytes).sum::<usize>(),
+        proof.to_bytes().len()
+    );
+
+    breakdown
+        .into_iter()
+        .map(|(name, bytes)| (name, bytes as u32))
+        .collect()
+}
@@ -0,0 +1,205 @@
+use miden::{
+    math::{Felt, FieldElement, StarkField},
+    AdviceInputs, Assembler, Kernel, MemAdviceProvider, Program, ProgramInfo, ProofOptions,
+    StackInputs, StackOutputs,
+};
+use rustbench::Benchmark;
+
+use super::{stark_proof_breakdown, with_proof_options, JobSpec};
+use crate::proof_options::describe;
+
+/// Performs `num_ops` read-modify-writes at pseudo-random addresses among the
+/// first `working_set` memory addresses and outputs the sum of the values read.
+/// The addresses come from a 32-bit LCG that the program runs itself, so the
+/// same sequence is used by the RISC Zero job.
+#[derive(Clone, Copy)]
+pub struct Spec {
+    pub num_ops: u32,
+    /// A power of two, so the address is the top bits of the LCG state.
+    pub working_set: u32,
+}
+
+pub struct Job {
+    spec: JobSpec<Spec>,
+    program: Program,
+    program_info: ProgramInfo,
+    program_inputs: StackInputs,
+    proof_options: ProofOptions,
+    program_outputs: StackOutputs,
+}
+
+pub fn new_jobs(proof_options: &[ProofOptions]) -> Vec<<Job as Benchmark>::Spec> {
+    let mut specs = Vec::new();
+    for working_set in [1 << 8, 1 << 12, 1 << 16, 1 << 20] {
+        for num_ops in [1000, 10_000] {
+            specs.push(Spec {
+                num_ops,
+                working_set,
+            });
+        }
+    }
+    with_proof_options(specs, proof_options)
+}
+
+const SEED: u32 = 1337;
+const LCG_MUL: u32 = 1664525;
+const LCG_ADD: u32 = 1013904223;
+
+impl Benchmark for Job {
+    const NAME: &'static str = "random_access";
+    type Spec = JobSpec<Spec>;
+    type ComputeOut = Vec<u64>;
+    type ProofType = miden::ExecutionProof;
+
+    fn job_size(spec: &Self::Spec) -> u32 {
+        spec.params.num_ops
+    }
+
+    fn output_size_bytes(output: &Self::ComputeOut, _proof: &Self::ProofType) -> u32 {
+        (output.len() * 8) as u32
+    }
+
+    fn proof_size_bytes(proof: &Self::ProofType) -> u32 {
+        proof.to_bytes().len() as u32
+    }
+
+    fn proof_size_breakdown(proof: &Self::ProofType) -> Vec<(&'static str, u32)> {
+        stark_proof_breakdown(proof)
+    }
+
+    fn new(spec: Self::Spec) -> Self {
+        let shift = 32 - spec.params.working_set.trailing_zeros();
+        let source = format!(
+            "
+            # stack start: [x=seed, sum=0, n, ...]
+            begin
+                dup.2 neq.0
+                while.true
+                    # next LCG state
+                    push.{mul} u32wrapping_mul push.{add} u32wrapping_add
+                    # [x, sum, n, ...] -> [addr, x, sum, n, ...]
+                    dup u32checked_shr.{shift}
+                    # read, add to the sum and write back the value plus x
+                    dup mem_load
+                    # [v, addr, x, sum, n, ...]
+                    dup movup.4 add movdn.4
+                    dup.2 add swap mem_store
+                    # [x, n, sum, ...] -> [x, sum, n - 1, ...]
+                    movup.2 swap
+                    movup.2 sub.1 movdn.2
+                    dup.2 neq.0
+                end
+                drop swap drop
+            end",
+            mul = LCG_MUL,
+            add = LCG_ADD,
+            shift = shift,
+        );
+
+        // The last value ends up on top of the stack
+        let input = vec![spec.params.num_ops as u64, 0, SEED as u64];
+        let program_inputs = StackInputs::try_from_values(input)
+            .map_err(|e| e.to_string())
+            .unwrap();
+
+        // Compiling the program
+        let assembler = Assembler::default();
+        let program = assembler
+            .compile(source.as_str())
+            .expect("Could not compile source");
+
+        let program_hash = program.hash();
+        let kernel = Kernel::default();
+        let program_info = ProgramInfo::new(program_hash, kernel);
+
+        let proof_options = spec.proof_options.clone();
+
+        let program_outputs = StackOutputs::new(vec![], vec![]);
+
+        Job {
+            spec,
+            program,
+            program_info,
+            program_inputs,
+            proof_options,
+            program_outputs,
+        }
+    }
+
+    fn spec(&self) -> &Self::Spec {
+        &self.spec
+    }
+
+    fn job_params(&self) -> String {
+        format!("working_set={}", self.spec.params.working_set)
+    }
+
+    fn proof_options(&self) -> String {
+        describe(&self.proof_options)
+    }
+
+    fn security_bits(proof: &Self::ProofType) -> Option<u32> {
+        Some(proof.security_level())
+    }
+
+    /// Compute on VM
+    fn guest_compute(&mut self) -> (Self::ComputeOut, Self::ProofType) {
+        let program = &self.program;
+        let program_input = self.program_inputs.clone();
+        let proof_options = self.proof_options.clone();
+
+        // Creating an empty advice provider
+        let advice_inputs = AdviceInputs::default()
+            .with_stack_values(vec![])
+            .map_err(|e| e.to_string());
+        let advice_provider = MemAdviceProvider::from(advice_inputs.unwrap());
+
+        let (output, proof) =
+            miden::prove(program, program_input, advice_provider, proof_options).expect("results");
+
+        let stack_output = output.stack_truncated(1).to_vec();
+
+        self.program_outputs = output;
+
+        (stack_output, proof)
+    }
+
+    /// Compute on host CPU
+    fn host_compute(&mut self) -> Option<Self::ComputeOut> {
+        let Spec {
+            num_ops,
+            working_set,
+        } = self.spec.params;
+        let shift = 32 - working_set.trailing_zeros();
+
+        let mut memory = vec![Felt::ZERO; working_set as usize];
+        let mut x = SEED;
+        let mut sum = Felt::ZERO;
+        for _ in 0..num_ops {
+            x = x.wrapping_mul(LCG_MUL).wrapping_add(LCG_ADD);
+            let addr = (x >> shift) as usize;
+            sum += memory[addr];
+            memory[addr] += Felt::from(x);
+        }
+
+        Some(vec![sum.as_int()])
+    }
+
+    fn verify_proof(&self, _output: &Self::ComputeOut, proof: &Self::ProofType) -> bool {
+        let program_info = self.program_info.clone();
+        let program_inputs = self.program_inputs.clone();
+        let program_outputs = self.program_outputs.clone();
+        let stark_proof = proof.clone();
+
+        let result = miden::verify(program_info, program_inputs, program_outputs, stark_proof)
+            .map_err(|err| format!("Program failed verification! - {}", err));
+
+        match result {
+            Ok(_) => true,
+            Err(err) => {
+                println!("{}", err);
+                false
+            }
+        }
+    }
+}
@@ -0,0 +1,243 @@
+use std::collections::HashSet;
+
+use miden::{
+    crypto::MerkleStore, AdviceInputs, Assembler, Kernel, MemAdviceProvider, Program, ProgramInfo,
+    ProofOptions, StackInputs, StackOutputs, Word,
+};
+use miden_core::crypto::merkle::SimpleSmt;
+use miden_core::{Felt, StarkField};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use rustbench::Benchmark;
+
+use super::{stark_proof_breakdown, with_proof_options, JobSpec};
+use crate::proof_options::describe;
+
+/// Applies `num_updates` key/value updates to a sparse Merkle tree of depth 32
+/// holding 1000 leaves and outputs the new root, as a rollup does when it
+/// transitions its state. Half of the updates overwrite existing leaves, the
+/// other half insert leaves at new keys.
+pub struct Job {
+    spec: JobSpec<u32>,
+    program: Program,
+    program_info: ProgramInfo,
+    program_inputs: StackInputs,
+    advice_provider: MemAdviceProvider,
+    proof_options: ProofOptions,
+    program_outputs: StackOutputs,
+    leaves: Vec<(u64, Word)>,
+    updates: Vec<(u64, Word)>,
+}
+
+pub fn new_jobs(proof_options: &[ProofOptions]) -> Vec<<Job as Benchmark>::Spec> {
+    with_proof_options(vec![10, 100, 1000], proof_options)
+}
+
+const DEPTH: u8 = 32;
+const NUM_LEAVES: usize = 1000;
+
+fn random_word(rand: &mut StdRng) -> Word {
+    [(); 4].map(|_| Felt::from(rand.gen::<u32>()))
+}
+
+impl Benchmark for Job {
+    const NAME: &'static str = "smt_update";
+    type Spec = JobSpec<u32>;
+    type ComputeOut = Vec<u64>;
+    type ProofType = miden::ExecutionProof;
+
+    fn job_size(spec: &Self::Spec) -> u32 {
+        spec.params
+    }
+
+    fn output_size_bytes(output: &Self::ComputeOut, _proof: &Self::ProofType) -> u32 {
+        (output.len() * 8) as u32
+    }
+
+    fn proof_size_bytes(proof: &Self::ProofType) -> u32 {
+        proof.to_bytes().len() as u32
+    }
+
+    fn proof_size_breakdown(proof: &Self::ProofType) -> Vec<(&'static str, u32)> {
+        stark_proof_breakdown(proof)
+    }
+
+    fn new(spec: Self::Spec) -> Self {
+        let num_updates = spec.params;
+        let source = format!(
+            "
+            begin
+                repeat.{}
+                    # stack = [R, ...]
+                    # read the new value and the index of the leaf from the
+                    # advice stack
+                    adv_push.4 swapw adv_push.1
+                    # [i, R, V, ...]
+
+                    # mtree_set needs [d, i, R, V, ...] and returns the old
+                    # value of the leaf together with the new root: [V_old, R', ...]
+                    push.{}
+                    mtree_set
+                    dropw
+                end
+            end",
+            num_updates, DEPTH
+        );
+
+        // Compiling the program
+        let assembler = Assembler::default();
+        let program = assembler
+            .compile(source.as_str())
+            .expect("Could not compile source");
+        let program_hash = program.hash();
+        let kernel = Kernel::default();
+        let program_info = ProgramInfo::new(program_hash, kernel);
+
+        let proof_options = spec.proof_options.clone();
+
+        let program_outputs = StackOutputs::new(vec![], vec![]);
+
+        // The tree starts with leaves at random keys. Every update either
+        // overwrites one of them or inserts a leaf at a key not used so far.
+        let mut rand = StdRng::seed_from_u64(1337);
+        let mut used = HashSet::new();
+        let mut random_key = |rand: &mut StdRng| loop {
+            let key = rand.gen_range(0..1u64 << DEPTH);
+            if used.insert(key) {
+                break key;
+            }
+        };
+
+        let leaves: Vec<(u64, Word)> = (0..NUM_LEAVES)
+            .map(|_| (random_key(&mut rand), random_word(&mut rand)))
+            .collect();
+        let updates: Vec<(u64, Word)> = (0..num_updates)
+            .map(|_| {
+                let key = if rand.gen::<bool>() {
+                    leaves[rand.gen_range(0..leaves.len())].0
+                } else {
+                    random_key(&mut rand)
+                };
+                (key, random_word(&mut rand))
+            })
+            .collect();
+
+        // mtree_set walks the tree from the root, so the store needs the paths
+        // of all populated leaves. New keys land in empty subtrees, whose nodes
+        // the store holds from the start.
+        let smt = SimpleSmt::new(DEPTH)
+            .unwrap()
+            .with_leaves(leaves.iter().copied())
+            .unwrap();
+        let all_paths = leaves
+            .iter()
+            .map(|(key, value)| (*key, *value, smt.get_leaf_path(*key).unwrap()));
+        let mut merkle_store = MerkleStore::new();
+        let root = merkle_store.add_merkle_paths(all_paths).unwrap();
+        assert_eq!(root, smt.root());
+
+        // For every update the advice stack holds the new value followed by the
+        // key: [V, i, V, i, ...]
+        let advice_stack: Vec<u64> = updates
+            .iter()
+            .flat_map(|(key, value)| {
+                value
+                    .iter()
+                    .map(|x| x.as_int())
+                    .chain(std::iter::once(*key))
+            })
+            .collect();
+
+        let advice_set = AdviceInputs::default()
+            .with_stack_values(advice_stack)
+            .unwrap()
+            .with_merkle_store(merkle_store);
+
+        let advice_provider = MemAdviceProvider::from(advice_set);
+
+        // The last value ends up on top, so the root is [R, ...]
+        let stack_init: Vec<u64> = root.iter().map(|x| x.as_int()).collect();
+        let program_inputs = StackInputs::try_from_values(stack_init)
+            .map_err(|e| e.to_string())
+            .unwrap();
+
+        Job {
+            spec,
+            program,
+            program_info,
+            program_inputs,
+            advice_provider,
+            proof_options,
+            program_outputs,
+            leaves,
+            updates,
+        }
+    }
+
+    fn spec(&self) -> &Self::Spec {
+        &self.spec
+    }
+
+    fn job_params(&self) -> String {
+        format!("depth={} leaves={}", DEPTH, NUM_LEAVES)
+    }
+
+    fn proof_options(&self) -> String {
+        describe(&self.proof_options)
+    }
+
+    fn security_bits(proof: &Self::ProofType) -> Option<u32> {
+        Some(proof.security_level())
+    }
+
+    /// Compute on host CPU
+    fn host_compute(&mut self) -> Option<Self::ComputeOut> {
+        let mut smt = SimpleSmt::new(DEPTH)
+            .unwrap()
+            .with_leaves(self.leaves.iter().copied())
+            .unwrap();
+        for (key, value) in self.updates.iter() {
+            smt.insert_leaf(*key, *value).unwrap();
+        }
+
+        Some(smt.root().iter().map(|x| x.as_int()).collect())
+    }
+
+    /// Compute on VM
+    fn guest_compute(&mut self) -> (Self::ComputeOut, Self::ProofType) {
+        let program = self.program.clone();
+        let program_inputs = self.program_inputs.clone();
+        let proof_options = self.proof_options.clone();
+        let advice_provider = self.advice_provider.clone();
+
+        let (output, proof) =
+            miden::prove(&program, program_inputs, advice_provider, proof_options)
+                .expect("results");
+
+        // The new root with its last element on top, reversed to [R]
+        let mut stack_output = output.stack_truncated(4).to_vec();
+        stack_output.reverse();
+
+        self.program_outputs = output;
+
+        (stack_output, proof)
+    }
+
+    fn verify_proof(&self, _output: &Self::ComputeOut, proof: &Self::ProofType) -> bool {
+        let program_info = self.program_info.clone();
+        let program_inputs = self.program_inputs.clone();
+        let program_outputs = self.program_outputs.clone();
+
+        let stark_proof = proof.clone();
+
+        let result = miden::verify(program_info, program_inputs, program_outputs, stark_proof)
+            .map_err(|err| format!("Program failed verification! - {}", err));
+
+        match result {
+            Ok(_) => true,
+            Err(err) => {
+                println!("{}", err);
+                false
+            }
+        }
+    }
+}
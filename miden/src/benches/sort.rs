@@ -0,0 +1,266 @@
+use miden::{
+    math::{Felt, StarkField},
+    AdviceInputs, Assembler, Kernel, MemAdviceProvider, Program, ProgramInfo, ProofOptions,
+    StackInputs, StackOutputs,
+};
+use miden_core::chiplets;
+use rand::{rngs::StdRng, RngCore, SeedableRng};
+use rustbench::Benchmark;
+
+use super::{stark_proof_breakdown, with_proof_options, JobSpec};
+use crate::proof_options::describe;
+
+/// Sorts a random array of u32 values in memory and outputs the Rescue Prime
+/// hash of the sorted array. The sort is an LSD radix sort with four passes
+/// over 8-bit digits, so every pass is a few linear sweeps over memory.
+pub struct Job {
+    spec: JobSpec<u32>,
+    program: Program,
+    program_info: ProgramInfo,
+    program_inputs: StackInputs,
+    proof_options: ProofOptions,
+    program_outputs: StackOutputs,
+    values: Vec<u64>,
+}
+
+pub fn new_jobs(proof_options: &[ProofOptions]) -> Vec<<Job as Benchmark>::Spec> {
+    with_proof_options(vec![1 << 8, 1 << 9, 1 << 10, 1 << 11], proof_options)
+}
+
+/// The random values to sort, the same as in the RISC Zero job.
+fn random_values(n: usize) -> Vec<u64> {
+    let mut rand = StdRng::seed_from_u64(1337);
+    (0..n).map(|_| rand.next_u32() as u64).collect()
+}
+
+/// One radix sort pass over the digit at `shift`, moving the `n` values at
+/// `src` to `dst` with the 256 bucket counters at `counts`.
+fn radix_pass(n: usize, shift: u32, src: usize, dst: usize, counts: usize) -> String {
+    let digit = match shift {
+        0 => String::from("push.255 u32checked_and"),
+        _ => format!("u32checked_shr.{} push.255 u32checked_and", shift),
+    };
+    let clear_counts = (0..256)
+        .map(|b| format!("push.0 mem_store.{}", counts + b))
+        .collect::<Vec<String>>()
+        .join("\n");
+    // counts[b] = sum of counts[0..b], with the running sum on the stack
+    let prefix_sum = (0..256)
+        .map(|b| format!("mem_load.{0} dup.1 mem_store.{0} add", counts + b))
+        .collect::<Vec<String>>()
+        .join("\n");
+
+    format!(
+        "
+        # digit at bit {shift}
+        {clear_counts}
+
+        # count the values per bucket
+        push.0
+        push.1
+        while.true
+            # [i, ...]
+            dup add.{src} mem_load
+            {digit}
+            add.{counts} dup mem_load add.1 swap mem_store
+            add.1 dup neq.{n}
+        end
+        drop
+
+        # turn the counts into the first position of every bucket
+        push.0
+        {prefix_sum}
+        drop
+
+        # move every value to the next free position of its bucket
+        push.0
+        push.1
+        while.true
+            # [i, ...]
+            dup add.{src} mem_load
+            dup {digit}
+            # [b, v, i, ...]
+            add.{counts} dup mem_load
+            # [p, addr, v, i, ...]
+            dup add.1 movup.2 mem_store
+            add.{dst} mem_store
+            add.1 dup neq.{n}
+        end
+        drop
+        "
+    )
+}
+
+impl Benchmark for Job {
+    const NAME: &'static str = "sort";
+    type Spec = JobSpec<u32>;
+    type ComputeOut = Vec<u64>;
+    type ProofType = miden::ExecutionProof;
+
+    fn job_size(spec: &Self::Spec) -> u32 {
+        spec.params
+    }
+
+    fn output_size_bytes(output: &Self::ComputeOut, _proof: &Self::ProofType) -> u32 {
+        (output.len() * 8) as u32
+    }
+
+    fn proof_size_bytes(proof: &Self::ProofType) -> u32 {
+        proof.to_bytes().len() as u32
+    }
+
+    fn proof_size_breakdown(proof: &Self::ProofType) -> Vec<(&'static str, u32)> {
+        stark_proof_breakdown(proof)
+    }
+
+    fn new(spec: Self::Spec) -> Self {
+        let n = spec.params as usize;
+        // Memory holds one element per address: the values at [0, n), the
+        // scratch buffer at [n, 2n) and the bucket counters at [2n, 2n + 256).
+        // After an even number of passes the sorted values are back at [0, n).
+        let passes = (0..4)
+            .map(|d| {
+                let (src, dst) = if d % 2 == 0 { (0, n) } else { (n, 0) };
+                radix_pass(n, 8 * d, src, dst, 2 * n)
+            })
+            .collect::<Vec<String>>()
+            .join("\n");
+        // Pushes the next 8 values; the pointer sits below those already
+        // pushed and the capacity.
+        let load_block = (0..8)
+            .map(|m| format!("dup.{} add.{} mem_load", 4 + m, m))
+            .collect::<Vec<String>>()
+            .join(" ");
+        // n is a multiple of 8, so the values fill the rate of the hasher exactly.
+        let source = format!(
+            "
+            begin
+                # read the values from the advice stack into memory
+                push.{n} push.0
+                # [addr, count, ...]
+                dup.1 neq.0
+                while.true
+                    adv_push.1 dup.1 mem_store
+                    add.1 swap sub.1 swap
+                    dup.1 neq.0
+                end
+                drop drop
+
+                {passes}
+
+                # hash the sorted values, absorbing 8 elements per permutation
+                push.0
+                padw padw padw
+                # [rate, rate, capacity, ptr, ...]
+                repeat.{blocks}
+                    dropw dropw
+                    {load_block}
+                    # [e_7, .., e_0, capacity, ptr, ...]
+                    hperm
+                    movup.12 add.8 movdn.12
+                end
+
+                # the digest is the first half of the rate
+                dropw swapw dropw movup.4 drop
+            end",
+            n = n,
+            passes = passes,
+            blocks = n / 8,
+            load_block = load_block,
+        );
+
+        let values = random_values(n);
+
+        let program_inputs = StackInputs::try_from_values(vec![])
+            .map_err(|e| e.to_string())
+            .unwrap();
+
+        // Compiling the program
+        let assembler = Assembler::default();
+        let program = assembler
+            .compile(source.as_str())
+            .expect("Could not compile source");
+
+        let program_hash = program.hash();
+        let kernel = Kernel::default();
+        let program_info = ProgramInfo::new(program_hash, kernel);
+
+        let proof_options = spec.proof_options.clone();
+
+        let program_outputs = StackOutputs::new(vec![], vec![]);
+
+        Job {
+            spec,
+            program,
+            program_info,
+            program_inputs,
+            proof_options,
+            program_outputs,
+            values,
+        }
+    }
+
+    fn spec(&self) -> &Self::Spec {
+        &self.spec
+    }
+
+    fn proof_options(&self) -> String {
+        describe(&self.proof_options)
+    }
+
+    fn security_bits(proof: &Self::ProofType) -> Option<u32> {
+        Some(proof.security_level())
+    }
+
+    /// Compute on VM
+    fn guest_compute(&mut self) -> (Self::ComputeOut, Self::ProofType) {
+        let program = &self.program;
+        let program_input = self.program_inputs.clone();
+        let proof_options = self.proof_options.clone();
+
+        // The values are read from the advice stack
+        let advice_inputs = AdviceInputs::default()
+            .with_stack_values(self.values.clone())
+            .map_err(|e| e.to_string());
+        let advice_provider = MemAdviceProvider::from(advice_inputs.unwrap());
+
+        let (output, proof) =
+            miden::prove(program, program_input, advice_provider, proof_options).expect("results");
+
+        let mut stack_output = output.stack_truncated(4).to_vec();
+        stack_output.reverse();
+
+        self.program_outputs = output;
+
+        (stack_output, proof)
+    }
+
+    /// Compute on host CPU
+    fn host_compute(&mut self) -> Option<Self::ComputeOut> {
+        let mut sorted = self.values.clone();
+        sorted.sort_unstable();
+
+        let elements: Vec<Felt> = sorted.into_iter().map(Felt::new).collect();
+        let digest = chiplets::hasher::hash_elements(&elements);
+
+        Some(digest.as_elements().iter().map(|x| x.as_int()).collect())
+    }
+
+    fn verify_proof(&self, _output: &Self::ComputeOut, proof: &Self::ProofType) -> bool {
+        let program_info = self.program_info.clone();
+        let program_inputs = self.program_inputs.clone();
+        let program_outputs = self.program_outputs.clone();
+        let stark_proof = proof.clone();
+
+        let result = miden::verify(program_info, program_inputs, program_outputs, stark_proof)
+            .map_err(|err| format!("Program failed verification! - {}", err));
+
+        match result {
+            Ok(_) => true,
+            Err(err) => {
+                println!("{}", err);
+                false
+            }
+        }
+    }
+}
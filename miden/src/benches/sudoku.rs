@@ -0,0 +1,299 @@
+use miden::{
+    math::{Felt, StarkField},
+    AdviceInputs, Assembler, Kernel, MemAdviceProvider, Program, ProgramInfo, ProofOptions,
+    StackInputs, StackOutputs,
+};
+use miden_core::chiplets;
+use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
+use rustbench::Benchmark;
+
+use super::{stark_proof_breakdown, with_proof_options, JobSpec};
+use crate::proof_options::describe;
+
+/// Checks the solutions of `spec` Sudoku puzzles and outputs the Rescue Prime
+/// hash of the puzzles. The solutions are read from the advice stack only, so
+/// they appear neither in the public inputs nor in the outputs.
+pub struct Job {
+    spec: JobSpec<u32>,
+    program: Program,
+    program_info: ProgramInfo,
+    program_inputs: StackInputs,
+    proof_options: ProofOptions,
+    program_outputs: StackOutputs,
+    puzzles: Vec<([u8; 81], [u8; 81])>,
+}
+
+pub fn new_jobs(proof_options: &[ProofOptions]) -> Vec<<Job as Benchmark>::Spec> {
+    with_proof_options(vec![1, 10, 100], proof_options)
+}
+
+/// Rows or columns in a random order that keeps them within their band.
+fn shuffled_lines(rand: &mut StdRng) -> Vec<usize> {
+    let mut bands = [0, 1, 2];
+    bands.shuffle(rand);
+    bands
+        .iter()
+        .flat_map(|band| {
+            let mut lines = [0, 1, 2];
+            lines.shuffle(rand);
+            lines.map(|line| 3 * band + line)
+        })
+        .collect()
+}
+
+/// Random puzzles with their solutions, the same as in the RISC Zero job.
+/// Every solution is a shuffled copy of a fixed grid, and about half of its
+/// cells are cleared to get the puzzle. Empty cells are 0.
+fn random_puzzles(n: usize) -> Vec<([u8; 81], [u8; 81])> {
+    let mut rand = StdRng::seed_from_u64(1337);
+    (0..n)
+        .map(|_| {
+            let mut digits: Vec<u8> = (1..=9).collect();
+            digits.shuffle(&mut rand);
+            let rows = shuffled_lines(&mut rand);
+            let cols = shuffled_lines(&mut rand);
+
+            let mut solution = [0u8; 81];
+            for (i, cell) in solution.iter_mut().enumerate() {
+                let (r, c) = (rows[i / 9], cols[i % 9]);
+                *cell = digits[(3 * (r % 3) + r / 3 + c) % 9];
+            }
+            let mut puzzle = solution;
+            for cell in puzzle.iter_mut() {
+                if rand.gen::<bool>() {
+                    *cell = 0;
+                }
+            }
+            (puzzle, solution)
+        })
+        .collect()
+}
+
+/// The cells of the 9 rows, 9 columns and 9 boxes.
+fn groups() -> Vec<Vec<usize>> {
+    let rows = (0..9).map(|r| (0..9).map(|c| 9 * r + c).collect());
+    let cols = (0..9).map(|c| (0..9).map(|r| 9 * r + c).collect());
+    let boxes = (0..9).map(|b| {
+        (0..9)
+            .map(|i| 9 * (3 * (b / 3) + i / 3) + 3 * (b % 3) + i % 3)
+            .collect()
+    });
+    rows.chain(cols).chain(boxes).collect()
+}
+
+/// A puzzle packed into 8 elements of up to 11 decimal digits, one digit per
+/// cell with the first cell most significant, so it fills the rate of the
+/// hasher exactly.
+fn pack(puzzle: &[u8; 81]) -> Vec<u64> {
+    puzzle
+        .chunks(11)
+        .map(|cells| cells.iter().fold(0, |acc, cell| 10 * acc + *cell as u64))
+        .collect()
+}
+
+impl Benchmark for Job {
+    const NAME: &'static str = "sudoku";
+    type Spec = JobSpec<u32>;
+    type ComputeOut = Vec<u64>;
+    type ProofType = miden::ExecutionProof;
+
+    fn job_size(spec: &Self::Spec) -> u32 {
+        spec.params
+    }
+
+    fn output_size_bytes(output: &Self::ComputeOut, _proof: &Self::ProofType) -> u32 {
+        (output.len() * 8) as u32
+    }
+
+    fn proof_size_bytes(proof: &Self::ProofType) -> u32 {
+        proof.to_bytes().len() as u32
+    }
+
+    fn proof_size_breakdown(proof: &Self::ProofType) -> Vec<(&'static str, u32)> {
+        stark_proof_breakdown(proof)
+    }
+
+    fn new(spec: Self::Spec) -> Self {
+        // Memory holds the puzzle at [0, 81) and 2^(s - 1) for every cell s of
+        // the solution at [81, 162).
+        let read_puzzle = (0..81)
+            .collect::<Vec<usize>>()
+            .chunks(11)
+            .map(|cells| {
+                let digits = cells
+                    .iter()
+                    .map(|i| format!("mul.10 adv_push.1 dup mem_store.{} add", i))
+                    .collect::<Vec<String>>()
+                    .join("\n");
+                format!("push.0\n{}", digits)
+            })
+            .collect::<Vec<String>>()
+            .join("\n");
+        // Every cell is a digit from 1 to 9 that matches the puzzle unless the
+        // puzzle cell is empty: p * (p - s) = 0
+        let read_solution = (0..81)
+            .map(|i| {
+                format!(
+                    "adv_push.1 dup sub.1 push.9 u32checked_lt assert
+                    dup mem_load.{} dup movup.2 sub mul assertz
+                    sub.1 pow2 mem_store.{}",
+                    i,
+                    81 + i
+                )
+            })
+            .collect::<Vec<String>>()
+            .join("\n");
+        // Nine powers of two add up to 2^9 - 1 only if they are all different
+        let check_groups = groups()
+            .iter()
+            .map(|cells| {
+                let sum = cells
+                    .iter()
+                    .map(|i| format!("mem_load.{} add", 81 + i))
+                    .collect::<Vec<String>>()
+                    .join(" ");
+                format!("push.0 {} push.511 assert_eq", sum)
+            })
+            .collect::<Vec<String>>()
+            .join("\n");
+
+        let source = format!(
+            "
+            begin
+                padw padw padw
+                # [rate, rate, capacity, ...]
+                repeat.{n}
+                    dropw dropw
+
+                    # read the puzzle from the advice stack into memory and
+                    # pack it into the rate: [e_7, .., e_0, capacity, ...]
+                    {read_puzzle}
+                    hperm
+
+                    # read and check the solution
+                    {read_solution}
+                    {check_groups}
+                end
+
+                # the digest is the first half of the rate
+                dropw swapw dropw
+            end",
+            n = spec.params,
+            read_puzzle = read_puzzle,
+            read_solution = read_solution,
+            check_groups = check_groups,
+        );
+
+        let puzzles = random_puzzles(spec.params as usize);
+
+        let program_inputs = StackInputs::try_from_values(vec![])
+            .map_err(|e| e.to_string())
+            .unwrap();
+
+        // Compiling the program
+        let assembler = Assembler::default();
+        let program = assembler
+            .compile(source.as_str())
+            .expect("Could not compile source");
+
+        let program_hash = program.hash();
+        let kernel = Kernel::default();
+        let program_info = ProgramInfo::new(program_hash, kernel);
+
+        let proof_options = spec.proof_options.clone();
+
+        let program_outputs = StackOutputs::new(vec![], vec![]);
+
+        Job {
+            spec,
+            program,
+            program_info,
+            program_inputs,
+            proof_options,
+            program_outputs,
+            puzzles,
+        }
+    }
+
+    fn spec(&self) -> &Self::Spec {
+        &self.spec
+    }
+
+    fn proof_options(&self) -> String {
+        describe(&self.proof_options)
+    }
+
+    fn security_bits(proof: &Self::ProofType) -> Option<u32> {
+        Some(proof.security_level())
+    }
+
+    /// Compute on VM
+    fn guest_compute(&mut self) -> (Self::ComputeOut, Self::ProofType) {
+        let program = &self.program;
+        let program_input = self.program_inputs.clone();
+        let proof_options = self.proof_options.clone();
+
+        // Both the puzzles and the solutions are private inputs on the advice
+        // stack: [puzzle, solution, puzzle, solution, ...]
+        let advice_stack: Vec<u64> = self
+            .puzzles
+            .iter()
+            .flat_map(|(puzzle, solution)| puzzle.iter().chain(solution.iter()))
+            .map(|cell| *cell as u64)
+            .collect();
+        let advice_inputs = AdviceInputs::default()
+            .with_stack_values(advice_stack)
+            .map_err(|e| e.to_string());
+        let advice_provider = MemAdviceProvider::from(advice_inputs.unwrap());
+
+        let (output, proof) =
+            miden::prove(program, program_input, advice_provider, proof_options).expect("results");
+
+        let mut stack_output = output.stack_truncated(4).to_vec();
+        stack_output.reverse();
+
+        self.program_outputs = output;
+
+        (stack_output, proof)
+    }
+
+    /// Compute on host CPU
+    fn host_compute(&mut self) -> Option<Self::ComputeOut> {
+        for (puzzle, solution) in self.puzzles.iter() {
+            assert!(puzzle.iter().zip(solution).all(|(p, s)| *p == 0 || p == s));
+            for cells in groups() {
+                let mut digits: Vec<u8> = cells.iter().map(|i| solution[*i]).collect();
+                digits.sort_unstable();
+                assert_eq!(digits, (1..=9).collect::<Vec<u8>>());
+            }
+        }
+
+        let elements: Vec<Felt> = self
+            .puzzles
+            .iter()
+            .flat_map(|(puzzle, _)| pack(puzzle))
+            .map(Felt::new)
+            .collect();
+        let digest = chiplets::hasher::hash_elements(&elements);
+
+        Some(digest.as_elements().iter().map(|x| x.as_int()).collect())
+    }
+
+    fn verify_proof(&self, _output: &Self::ComputeOut, proof: &Self::ProofType) -> bool {
+        let program_info = self.program_info.clone();
+        let program_inputs = self.program_inputs.clone();
+        let program_outputs = self.program_outputs.clone();
+        let stark_proof = proof.clone();
+
+        let result = miden::verify(program_info, program_inputs, program_outputs, stark_proof)
+            .map_err(|err| format!("Program failed verification! - {}", err));
+
+        match result {
+            Ok(_) => true,
+            Err(err) => {
+                println!("{}", err);
+                false
+            }
+        }
+    }
+}
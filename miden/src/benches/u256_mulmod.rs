@@ -0,0 +1,343 @@
+use miden::{
+    AdviceInputs, Assembler, Kernel, MemAdviceProvider, Program, ProgramInfo, ProofOptions,
+    StackInputs, StackOutputs,
+};
+use miden_stdlib::StdLibrary;
+use num_bigint::BigUint;
+use rand::{rngs::StdRng, RngCore, SeedableRng};
+use rustbench::Benchmark;
+
+use super::{stark_proof_breakdown, with_proof_options, JobSpec};
+use crate::proof_options::describe;
+
+/// Computes a * b^n modulo the secp256k1 base field prime with n chained
+/// 256-bit modular multiplications. The VM has no native 256-bit arithmetic,
+/// so the modulus is an input like the operands and the program multiplies
+/// eight 32-bit limbs with u32 operations and `std::math::u64`, reducing with
+/// the same Montgomery method as crypto-bigint in the RISC Zero job.
+pub struct Job {
+    spec: JobSpec<u32>,
+    program: Program,
+    program_info: ProgramInfo,
+    program_inputs: StackInputs,
+    proof_options: ProofOptions,
+    program_outputs: StackOutputs,
+    a: BigUint,
+    b: BigUint,
+}
+
+pub fn new_jobs(proof_options: &[ProofOptions]) -> Vec<<Job as Benchmark>::Spec> {
+    with_proof_options(vec![1, 10, 100], proof_options)
+}
+
+/// The secp256k1 base field prime, 2^256 - 2^32 - 977
+fn modulus() -> BigUint {
+    (BigUint::from(1u32) << 256) - (BigUint::from(1u32) << 32) - BigUint::from(977u32)
+}
+
+/// Two random field elements, the same as in the RISC Zero job.
+fn random_operands() -> (BigUint, BigUint) {
+    let mut rand = StdRng::seed_from_u64(1337);
+    let mut random_element = || {
+        let mut bytes = [0u8; 32];
+        rand.fill_bytes(&mut bytes);
+        BigUint::from_bytes_le(&bytes) % modulus()
+    };
+    let a = random_element();
+    let b = random_element();
+    (a, b)
+}
+
+/// -p^-1 mod 2^32, the factor of a Montgomery reduction step modulo `p`.
+fn reduction_factor(p: &BigUint) -> u64 {
+    let p0 = limbs(p)[0] as u32;
+    // Newton's iteration doubles the number of correct low bits every step
+    let mut inverse = 1u32;
+    for _ in 0..5 {
+        inverse = inverse.wrapping_mul(2u32.wrapping_sub(p0.wrapping_mul(inverse)));
+    }
+    u64::from(inverse.wrapping_neg())
+}
+
+/// Memory addresses of the limbs, one limb per address.
+const X: u32 = 0;
+const Y: u32 = 8;
+const P: u32 = 16;
+const T: u32 = 24;
+const D: u32 = 34;
+const A: u32 = 42;
+const FACTOR: u32 = 50;
+const COUNTER: u32 = 51;
+
+/// Generates a procedure that sets x = x * y * 2^-256 mod p with the CIOS
+/// Montgomery multiplication, unrolled over the eight limbs of x, y and p.
+/// The intermediate t has two more limbs, and its final reduction writes to d.
+fn mont_mul() -> String {
+    let mut source = String::from("proc.mont_mul\n");
+    for k in 0..10 {
+        source += &format!("    push.0 mem_store.{}\n", T + k);
+    }
+
+    for i in 0..8 {
+        // t = t + x * y_i
+        source += "    push.0\n";
+        for j in 0..8 {
+            source += &format!(
+                "    mem_load.{y} mem_load.{x} u32overflowing_madd
+    mem_load.{t} push.0 exec.u64::wrapping_add
+    swap mem_store.{t}\n",
+                y = Y + i,
+                x = X + j,
+                t = T + j,
+            );
+        }
+        source += &format!(
+            "    mem_load.{t8} u32overflowing_add swap mem_store.{t8}
+    mem_store.{t9}\n",
+            t8 = T + 8,
+            t9 = T + 9,
+        );
+
+        // t = (t + m * p) / 2^32, where m makes the lowest limb vanish
+        source += &format!(
+            "    mem_load.{t} mem_load.{factor} u32wrapping_mul
+    mem_load.{t} dup.1 mem_load.{p} u32overflowing_madd swap drop\n",
+            t = T,
+            factor = FACTOR,
+            p = P,
+        );
+        for j in 1..8 {
+            source += &format!(
+                "    dup.1 mem_load.{p} u32overflowing_madd
+    mem_load.{t} push.0 exec.u64::wrapping_add
+    swap mem_store.{t_prev}\n",
+                p = P + j,
+                t = T + j,
+                t_prev = T + j - 1,
+            );
+        }
+        source += &format!(
+            "    mem_load.{t8} u32overflowing_add swap mem_store.{t7}
+    mem_load.{t9} add mem_store.{t8}
+    drop\n",
+            t7 = T + 7,
+            t8 = T + 8,
+            t9 = T + 9,
+        );
+    }
+
+    // t < 2p, so x is either t or d = t - p
+    source += "    push.0\n";
+    for j in 0..8 {
+        source += &format!(
+            "    mem_load.{t} mem_load.{p} u32overflowing_sub
+    movdn.2 swap u32overflowing_sub
+    movup.2 or swap mem_store.{d}\n",
+            t = T + j,
+            p = P + j,
+            d = D + j,
+        );
+    }
+    source += &format!("    mem_load.{} swap not or\n    if.true\n", T + 8);
+    for j in 0..8 {
+        source += &format!("        mem_load.{} mem_store.{}\n", D + j, X + j);
+    }
+    source += "    else\n";
+    for j in 0..8 {
+        source += &format!("        mem_load.{} mem_store.{}\n", T + j, X + j);
+    }
+    source += "    end\nend\n";
+    source
+}
+
+/// The eight 32-bit limbs of a 256-bit number, least significant first.
+fn limbs(x: &BigUint) -> Vec<u64> {
+    let mut limbs: Vec<u64> = x.iter_u32_digits().map(u64::from).collect();
+    limbs.resize(8, 0);
+    limbs
+}
+
+impl Benchmark for Job {
+    const NAME: &'static str = "u256_mulmod";
+    type Spec = JobSpec<u32>;
+    type ComputeOut = Vec<u64>;
+    type ProofType = miden::ExecutionProof;
+
+    fn job_size(spec: &Self::Spec) -> u32 {
+        spec.params
+    }
+
+    fn output_size_bytes(output: &Self::ComputeOut, _proof: &Self::ProofType) -> u32 {
+        (output.len() * 8) as u32
+    }
+
+    fn proof_size_bytes(proof: &Self::ProofType) -> u32 {
+        proof.to_bytes().len() as u32
+    }
+
+    fn proof_size_breakdown(proof: &Self::ProofType) -> Vec<(&'static str, u32)> {
+        stark_proof_breakdown(proof)
+    }
+
+    fn new(spec: Self::Spec) -> Self {
+        let num_iter = spec.params;
+
+        let store = |base: u32| {
+            (0..8)
+                .map(|j| format!("mem_store.{}", base + j))
+                .collect::<Vec<String>>()
+                .join(" ")
+        };
+        let copy = |from: u32, to: u32| {
+            (0..8)
+                .map(|j| format!("mem_load.{} mem_store.{}", from + j, to + j))
+                .collect::<Vec<String>>()
+                .join(" ")
+        };
+
+        let source = format!(
+            "
+            use.std::math::u64
+
+            {mont_mul}
+
+            # stack start: [a, b, p, r, factor, ...], eight limbs each with the
+            # least significant on top, where r = 2^512 mod p
+            begin
+                {store_a} {store_b} {store_p} {store_r}
+                mem_store.{factor}
+
+                # y = b * r / 2^256 = b * 2^256 mod p, so that every
+                # Montgomery multiplication by y is a multiplication by b
+                exec.mont_mul
+                {copy_x_y} {copy_a_x}
+
+                push.{num_iter} mem_store.{counter}
+                push.1
+                while.true
+                    exec.mont_mul
+                    mem_load.{counter} sub.1 dup mem_store.{counter} neq.0
+                end
+
+                {load_x}
+            end",
+            mont_mul = mont_mul(),
+            store_a = store(A),
+            store_b = store(X),
+            store_p = store(P),
+            store_r = store(Y),
+            factor = FACTOR,
+            copy_x_y = copy(X, Y),
+            copy_a_x = copy(A, X),
+            num_iter = num_iter,
+            counter = COUNTER,
+            load_x = (0..8)
+                .rev()
+                .map(|j| format!("mem_load.{}", X + j))
+                .collect::<Vec<String>>()
+                .join(" "),
+        );
+
+        let (a, b) = random_operands();
+
+        // The last value ends up on top of the stack
+        let p = modulus();
+        let r = (BigUint::from(1u32) << 512) % &p;
+        let mut input = limbs(&a);
+        input.extend(limbs(&b));
+        input.extend(limbs(&p));
+        input.extend(limbs(&r));
+        input.push(reduction_factor(&p));
+        input.reverse();
+        let program_inputs = StackInputs::try_from_values(input)
+            .map_err(|e| e.to_string())
+            .unwrap();
+
+        // compiling the program
+        let assembler = Assembler::default()
+            .with_library(&StdLibrary::default())
+            .expect("failed to load stdlib");
+
+        let program = assembler
+            .compile(source.as_str())
+            .expect("Could not compile source");
+        let program_hash = program.hash();
+        let kernel = Kernel::default();
+        let program_info = ProgramInfo::new(program_hash, kernel);
+
+        let proof_options = spec.proof_options.clone();
+
+        let program_outputs = StackOutputs::new(vec![], vec![]);
+
+        Job {
+            spec,
+            program,
+            program_info,
+            program_inputs,
+            proof_options,
+            program_outputs,
+            a,
+            b,
+        }
+    }
+
+    fn spec(&self) -> &Self::Spec {
+        &self.spec
+    }
+
+    fn proof_options(&self) -> String {
+        describe(&self.proof_options)
+    }
+
+    fn security_bits(proof: &Self::ProofType) -> Option<u32> {
+        Some(proof.security_level())
+    }
+
+    fn guest_compute(&mut self) -> (Self::ComputeOut, Self::ProofType) {
+        let program = self.program.clone();
+        let program_inputs = self.program_inputs.clone();
+        let proof_options = self.proof_options.clone();
+
+        // Creating an empty advice provider
+        let advice_inputs = AdviceInputs::default()
+            .with_stack_values(vec![])
+            .map_err(|e| e.to_string());
+        let advice_provider = MemAdviceProvider::from(advice_inputs.unwrap());
+
+        let (output, proof) =
+            miden::prove(&program, program_inputs, advice_provider, proof_options)
+                .expect("results");
+
+        // The limbs of the result, least significant first
+        let stack_output = output.stack_truncated(8).to_vec();
+
+        self.program_outputs = output;
+
+        (stack_output, proof)
+    }
+
+    fn host_compute(&mut self) -> Option<<Self as Benchmark>::ComputeOut> {
+        let p = modulus();
+        let result = &self.a * self.b.modpow(&BigUint::from(self.spec.params), &p) % &p;
+
+        Some(limbs(&result))
+    }
+
+    fn verify_proof(&self, _output: &Self::ComputeOut, proof: &Self::ProofType) -> bool {
+        let program_info = self.program_info.clone();
+        let program_inputs = self.program_inputs.clone();
+        let program_outputs = self.program_outputs.clone();
+        let stark_proof = proof.clone();
+
+        let result = miden::verify(program_info, program_inputs, program_outputs, stark_proof)
+            .map_err(|err| format!("Program failed verification! - {}", err));
+
+        match result {
+            Ok(_) => true,
+            Err(err) => {
+                println!("{}", err);
+                false
+            }
+        }
+    }
+}
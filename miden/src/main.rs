@@ -1,13 +1,28 @@
 use clap::{Parser, Subcommand};
 use std::path::PathBuf;
+use std::time::Duration;
 
 mod benches;
+mod proof_options;
+mod prover_info;
 
+use benches::big_rpo;
+use benches::fibonacci;
 use benches::iter_blake3;
+use benches::iter_keccak;
 use benches::iter_rescue_prime;
 use benches::iter_sha2;
+use benches::matmul;
+use benches::merkle_build;
 use benches::merkle_path_rescue_prime;
-use rustbench::{init_logging, run_jobs};
+use benches::random_access;
+use benches::smt_update;
+use benches::sort;
+use benches::sudoku;
+use benches::u256_mulmod;
+use proof_options::ProofOptionsArgs;
+use prover_info::MidenInfo;
+use rustbench::{init_logging, run_jobs, write_prover_info, RunConfig};
 
 #[derive(Parser)]
 #[command(author, version, about, long_about = None)]
@@ -16,6 +31,17 @@ struct Cli {
     #[arg(long, value_name = "FILE")]
     out: PathBuf,
 
+    // Number of times each proof is generated
+    #[arg(long, value_name = "N", default_value_t = 1)]
+    proof_runs: u32,
+
+    // Minimum time spent repeating proof verification, in milliseconds
+    #[arg(long, value_name = "MS", default_value_t = 1000)]
+    min_verify_ms: u64,
+
+    #[command(flatten)]
+    proof_options: ProofOptionsArgs,
+
     #[command(subcommand)]
     command: Command,
 }
@@ -23,35 +49,147 @@ struct Cli {
 #[derive(Eq, PartialEq, Subcommand)]
 enum Command {
     All,
+    BigRpo,
+    Fibonacci,
     IterBlake3,
+    IterKeccak,
     IterSha2,
     IterRescuePrime,
     MerklePathRescuePrime,
+    Matmul,
+    Sort,
+    RandomAccess,
+    SmtUpdate,
+    U256Mulmod,
+    Sudoku,
+    MerkleBuild,
 }
 
 fn main() {
     init_logging();
     let cli = Cli::parse();
 
+    let config = RunConfig {
+        proof_runs: cli.proof_runs,
+        min_verify_duration: Duration::from_millis(cli.min_verify_ms),
+    };
+
+    let proof_options = cli.proof_options.proof_options();
+
     let prover = String::from("miden");
 
+    for options in proof_options.iter() {
+        write_prover_info(&prover, &cli.out, &MidenInfo::new(options.clone()));
+    }
+
+    if cli.command == Command::All || cli.command == Command::BigRpo {
+        run_jobs::<big_rpo::Job>(
+            &prover,
+            &cli.out,
+            &config,
+            big_rpo::new_jobs(&proof_options),
+        );
+    }
+
+    if cli.command == Command::All || cli.command == Command::Fibonacci {
+        run_jobs::<fibonacci::Job>(
+            &prover,
+            &cli.out,
+            &config,
+            fibonacci::new_jobs(&proof_options),
+        );
+    }
+
     if cli.command == Command::All || cli.command == Command::IterBlake3 {
-        run_jobs::<iter_blake3::Job>(&prover, &cli.out, iter_blake3::new_jobs());
+        run_jobs::<iter_blake3::Job>(
+            &prover,
+            &cli.out,
+            &config,
+            iter_blake3::new_jobs(&proof_options),
+        );
+    }
+
+    if cli.command == Command::All || cli.command == Command::IterKeccak {
+        run_jobs::<iter_keccak::Job>(
+            &prover,
+            &cli.out,
+            &config,
+            iter_keccak::new_jobs(&proof_options),
+        );
     }
 
     if cli.command == Command::All || cli.command == Command::IterSha2 {
-        run_jobs::<iter_sha2::Job>(&prover, &cli.out, iter_sha2::new_jobs());
+        run_jobs::<iter_sha2::Job>(
+            &prover,
+            &cli.out,
+            &config,
+            iter_sha2::new_jobs(&proof_options),
+        );
     }
 
     if cli.command == Command::All || cli.command == Command::IterRescuePrime {
-        run_jobs::<iter_rescue_prime::Job>(&prover, &cli.out, iter_rescue_prime::new_jobs());
+        run_jobs::<iter_rescue_prime::Job>(
+            &prover,
+            &cli.out,
+            &config,
+            iter_rescue_prime::new_jobs(&proof_options),
+        );
     }
 
     if cli.command == Command::All || cli.command == Command::MerklePathRescuePrime {
         run_jobs::<merkle_path_rescue_prime::Job>(
             &prover,
             &cli.out,
-            merkle_path_rescue_prime::new_jobs(),
+            &config,
+            merkle_path_rescue_prime::new_jobs(&proof_options),
+        );
+    }
+
+    if cli.command == Command::All || cli.command == Command::Matmul {
+        run_jobs::<matmul::Job>(&prover, &cli.out, &config, matmul::new_jobs(&proof_options));
+    }
+
+    if cli.command == Command::All || cli.command == Command::Sort {
+        run_jobs::<sort::Job>(&prover, &cli.out, &config, sort::new_jobs(&proof_options));
+    }
+
+    if cli.command == Command::All || cli.command == Command::RandomAccess {
+        run_jobs::<random_access::Job>(
+            &prover,
+            &cli.out,
+            &config,
+            random_access::new_jobs(&proof_options),
+        );
+    }
+
+    if cli.command == Command::All || cli.command == Command::SmtUpdate {
+        run_jobs::<smt_update::Job>(
+            &prover,
+            &cli.out,
+            &config,
+            smt_update::new_jobs(&proof_options),
+        );
+    }
+
+    if cli.command == Command::All || cli.command == Command::U256Mulmod {
+        run_jobs::<u256_mulmod::Job>(
+            &prover,
+            &cli.out,
+            &config,
+            u256_mulmod::new_jobs(&proof_options),
+        );
+    }
+
+    if cli.command == Command::All || cli.command == Command::Sudoku {
+        run_jobs::<sudoku::Job>(&prover, &cli.out, &config, sudoku::new_jobs(&proof_options));
+    }
+
+    if cli.command == Command::All || cli.command == Command::MerkleBuild {
+        run_jobs::<merkle_build::Job>(
+            &prover,
+            &cli.out,
+            &config,
+            merkle_build::new_jobs(&proof_options),
         );
     }
 }
@@ -0,0 +1,96 @@
+use clap::{Args, ValueEnum};
+use miden::ProofOptions;
+use miden_prover::FieldExtension;
+use winter_air::ProofOptions as WinterProofOptions;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, ValueEnum)]
+pub enum Security {
+    #[value(name = "96")]
+    Bits96,
+    #[value(name = "128")]
+    Bits128,
+}
+
+/// Proof options selected on the command line. Every job is proven once
+/// per selected preset, with the custom parameters applied on top of it.
+#[derive(Args)]
+pub struct ProofOptionsArgs {
+    // Security presets to prove with; repeat to sweep over several
+    #[arg(long, value_enum, default_values_t = [Security::Bits96])]
+    security: Vec<Security>,
+
+    // Override the blowup factor (a power of two from 8 to 128) of the presets
+    #[arg(long, value_parser = parse_blowup_factor)]
+    blowup_factor: Option<usize>,
+
+    // Override the number of FRI queries (1 to 255) of the presets
+    #[arg(long, value_parser = clap::value_parser!(u32).range(1..=255))]
+    num_queries: Option<u32>,
+
+    // Override the number of proof-of-work grinding bits (0 to 32) of the presets
+    #[arg(long, value_parser = clap::value_parser!(u32).range(0..=32))]
+    grinding_bits: Option<u32>,
+
+    // Override the degree of the field extension (1, 2 or 3) of the presets
+    #[arg(long, value_parser = clap::value_parser!(u32).range(1..=3))]
+    field_extension: Option<u32>,
+}
+
+impl ProofOptionsArgs {
+    pub fn proof_options(&self) -> Vec<ProofOptions> {
+        self.security
+            .iter()
+            .map(|security| {
+                let preset = match security {
+                    Security::Bits96 => ProofOptions::with_96_bit_security(),
+                    Security::Bits128 => ProofOptions::with_128_bit_security(),
+                };
+                let hash_fn = preset.hash_fn();
+                let preset = WinterProofOptions::from(preset);
+
+                let field_extension = match self.field_extension {
+                    Some(1) => FieldExtension::None,
+                    Some(2) => FieldExtension::Quadratic,
+                    Some(3) => FieldExtension::Cubic,
+                    _ => preset.field_extension(),
+                };
+
+                ProofOptions::new(
+                    self.num_queries
+                        .map_or(preset.num_queries(), |num_queries| num_queries as usize),
+                    self.blowup_factor.unwrap_or(preset.blowup_factor()),
+                    self.grinding_bits.unwrap_or(preset.grinding_factor()),
+                    field_extension,
+                    preset.to_fri_options().folding_factor(),
+                    preset.to_fri_options().remainder_max_degree(),
+                    hash_fn,
+                )
+            })
+            .collect()
+    }
+}
+
+/// Accepts the blowup factors Miden VM can prove with: the degree of its
+/// constraints needs at least 8 and winter-air allows at most 128. Other
+/// values are rejected on the command line instead of panicking in the prover.
+fn parse_blowup_factor(value: &str) -> Result<usize, String> {
+    let blowup_factor: usize = value.parse().map_err(|err| format!("{}", err))?;
+    if !blowup_factor.is_power_of_two() || !(8..=128).contains(&blowup_factor) {
+        return Err(String::from("must be a power of two from 8 to 128"));
+    }
+    Ok(blowup_factor)
+}
+
+/// Formats proof options for the benchmark output.
+pub fn describe(options: &ProofOptions) -> String {
+    let hash_fn = options.hash_fn();
+    let options = WinterProofOptions::from(options.clone());
+    format!(
+        "hash={:?} blowup={} queries={} grinding={} extension={}",
+        hash_fn,
+        options.blowup_factor(),
+        options.num_queries(),
+        options.grinding_factor(),
+        options.field_extension().degree(),
+    )
+}
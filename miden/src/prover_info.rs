@@ -0,0 +1,60 @@
+use miden::ProofOptions;
+use rustbench::ProverInfo;
+use winter_air::ProofOptions as WinterProofOptions;
+
+/// Describes Miden VM proven with a given set of proof options.
+pub struct MidenInfo {
+    proof_options: ProofOptions,
+}
+
+impl MidenInfo {
+    pub fn new(proof_options: ProofOptions) -> Self {
+        MidenInfo { proof_options }
+    }
+}
+
+impl ProverInfo for MidenInfo {
+    fn name(&self) -> String {
+        String::from("Polygon Miden")
+    }
+
+    fn repository(&self) -> String {
+        String::from("https://github.com/0xPolygonMiden/miden-vm")
+    }
+
+    fn proof_system(&self) -> String {
+        String::from("STARK")
+    }
+
+    fn field(&self) -> String {
+        String::from("Goldilocks (2^64 - 2^32 + 1)")
+    }
+
+    fn commitment_hash(&self) -> String {
+        format!("{:?}", self.proof_options.hash_fn())
+    }
+
+    /// Conjectured security from the number of queries, blowup factor and grinding,
+    /// capped by the collision resistance of the commitment hash. The field size
+    /// term depends on the trace length and is ignored here; it does not limit the
+    /// presets. The security level of each individual proof is reported with its
+    /// results.
+    fn security_bits(&self) -> u32 {
+        let hash_fn = self.proof_options.hash_fn();
+        let options = WinterProofOptions::from(self.proof_options.clone());
+
+        let query_security = options.blowup_factor().ilog2() * options.num_queries() as u32
+            + options.grinding_factor();
+
+        (query_security - 1).min(hash_fn.collision_resistance())
+    }
+
+    fn post_quantum(&self) -> bool {
+        true
+    }
+
+    fn crate_version(&self) -> String {
+        // Locked version, see build.rs
+        String::from(env!("MIDEN_VERSION"))
+    }
+}
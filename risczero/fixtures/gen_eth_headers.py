@@ -0,0 +1,129 @@
+#!/usr/bin/env python3
+"""Generates the synthetic Ethereum block headers used by the eth_headers job.
+
+The headers follow the London layout (16 fields, with the base fee) and form
+a chain: every header's parent_hash is the Keccak-256 hash of the RLP encoding
+of the previous header. The output is the RLP encoding of every header,
+concatenated, so the file can be regenerated byte for byte:
+
+    python3 gen_eth_headers.py > eth_headers.rlp
+"""
+
+import random
+import sys
+
+NUM_HEADERS = 100
+FIRST_NUMBER = 17_000_000
+FIRST_TIMESTAMP = 1_681_338_455
+GAS_LIMIT = 30_000_000
+
+# Keccak-f[1600] round constants and rotation offsets
+ROUND_CONSTANTS = [
+    0x0000000000000001, 0x0000000000008082, 0x800000000000808A, 0x8000000080008000,
+    0x000000000000808B, 0x0000000080000001, 0x8000000080008081, 0x8000000000008009,
+    0x000000000000008A, 0x0000000000000088, 0x0000000080008009, 0x000000008000000A,
+    0x000000008000808B, 0x800000000000008B, 0x8000000000008089, 0x8000000000008003,
+    0x8000000000008002, 0x8000000000000080, 0x000000000000800A, 0x800000008000000A,
+    0x8000000080008081, 0x8000000000008080, 0x0000000080000001, 0x8000000080008008,
+]
+ROTATIONS = [
+    [0, 36, 3, 41, 18],
+    [1, 44, 10, 45, 2],
+    [62, 6, 43, 15, 61],
+    [28, 55, 25, 21, 56],
+    [27, 20, 39, 8, 14],
+]
+MASK = (1 << 64) - 1
+
+
+def rotl(x, n):
+    return ((x << n) | (x >> (64 - n))) & MASK if n else x
+
+
+def keccak_f(state):
+    for rc in ROUND_CONSTANTS:
+        c = [state[x][0] ^ state[x][1] ^ state[x][2] ^ state[x][3] ^ state[x][4] for x in range(5)]
+        d = [c[(x - 1) % 5] ^ rotl(c[(x + 1) % 5], 1) for x in range(5)]
+        state = [[state[x][y] ^ d[x] for y in range(5)] for x in range(5)]
+        b = [[0] * 5 for _ in range(5)]
+        for x in range(5):
+            for y in range(5):
+                b[y][(2 * x + 3 * y) % 5] = rotl(state[x][y], ROTATIONS[x][y])
+        state = [[b[x][y] ^ (~b[(x + 1) % 5][y] & b[(x + 2) % 5][y]) for y in range(5)] for x in range(5)]
+        state[0][0] ^= rc
+    return state
+
+
+def keccak256(data):
+    rate = 136
+    # Keccak padding, not the SHA-3 one
+    padded = bytearray(data) + b"\x01" + b"\x00" * ((-len(data) - 2) % rate) + b"\x80"
+    if len(padded) % rate:
+        raise AssertionError("bad padding")
+    state = [[0] * 5 for _ in range(5)]
+    for offset in range(0, len(padded), rate):
+        block = padded[offset:offset + rate]
+        for i in range(rate // 8):
+            state[i % 5][i // 5] ^= int.from_bytes(block[8 * i:8 * i + 8], "little")
+        state = keccak_f(state)
+    return b"".join(state[i % 5][i // 5].to_bytes(8, "little") for i in range(4))
+
+
+def rlp_length(length, offset):
+    if length < 56:
+        return bytes([offset + length])
+    encoded = length.to_bytes((length.bit_length() + 7) // 8, "big")
+    return bytes([offset + 55 + len(encoded)]) + encoded
+
+
+def rlp_bytes(data):
+    if len(data) == 1 and data[0] < 0x80:
+        return bytes(data)
+    return rlp_length(len(data), 0x80) + data
+
+
+def rlp_int(value):
+    return rlp_bytes(value.to_bytes((value.bit_length() + 7) // 8, "big"))
+
+
+def rlp_list(items):
+    payload = b"".join(items)
+    return rlp_length(len(payload), 0xC0) + payload
+
+
+def main():
+    rand = random.Random(1337)
+
+    def random_bytes(n):
+        return rand.getrandbits(8 * n).to_bytes(n, "big")
+
+    empty_ommers_hash = keccak256(rlp_list([]))
+    parent_hash = bytes(32)
+    out = bytearray()
+    for i in range(NUM_HEADERS):
+        header = rlp_list([
+            rlp_bytes(parent_hash),
+            rlp_bytes(empty_ommers_hash),
+            rlp_bytes(random_bytes(20)),  # beneficiary
+            rlp_bytes(random_bytes(32)),  # state root
+            rlp_bytes(random_bytes(32)),  # transactions root
+            rlp_bytes(random_bytes(32)),  # receipts root
+            rlp_bytes(random_bytes(256)),  # logs bloom
+            rlp_int(0),  # difficulty
+            rlp_int(FIRST_NUMBER + i),
+            rlp_int(GAS_LIMIT),
+            rlp_int(rand.randrange(GAS_LIMIT)),  # gas used
+            rlp_int(FIRST_TIMESTAMP + 12 * i),
+            rlp_bytes(random_bytes(rand.randrange(33))),  # extra data
+            rlp_bytes(random_bytes(32)),  # mix hash
+            rlp_bytes(bytes(8)),  # nonce
+            rlp_int(rand.randrange(10**9, 10**11)),  # base fee per gas
+        ])
+        out += header
+        parent_hash = keccak256(header)
+
+    sys.stdout.buffer.write(out)
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,44 @@
+#![no_std]
+#![no_main]
+
+use k256::ecdsa::{signature::hazmat::PrehashVerifier, Signature, VerifyingKey};
+use risc0_zkvm::guest::env;
+use risc0_zkvm::{sha, sha::Sha256};
+
+risc0_zkvm::entry!(main);
+
+const PUBLIC_KEY_LEN: usize = 33;
+const MESSAGE_LEN: usize = 64;
+const SIGNATURE_LEN: usize = 64;
+
+pub fn main() {
+    let data: &[u8] = env::send_recv(0, &[]);
+
+    let num_signatures = u32::from_le_bytes(data[0..4].try_into().unwrap());
+
+    // Each record is a compressed public key, the message and the signature
+    let records = data[4..].chunks_exact(PUBLIC_KEY_LEN + MESSAGE_LEN + SIGNATURE_LEN);
+
+    let mut num_valid: u32 = 0;
+    for record in records.take(num_signatures as usize) {
+        let (public_key, rest) = record.split_at(PUBLIC_KEY_LEN);
+        let (message, signature) = rest.split_at(MESSAGE_LEN);
+
+        // The message digest uses the SHA-256 accelerator, the curve arithmetic
+        // runs in software
+        let digest = sha::Impl::hash_bytes(message);
+        let valid = match (
+            VerifyingKey::from_sec1_bytes(public_key),
+            Signature::try_from(signature),
+        ) {
+            (Ok(key), Ok(signature)) => key.verify_prehash(digest.as_bytes(), &signature).is_ok(),
+            _ => false,
+        };
+
+        if valid {
+            num_valid += 1;
+        }
+    }
+
+    env::commit(&num_valid)
+}
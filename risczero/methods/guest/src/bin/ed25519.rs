@@ -0,0 +1,38 @@
+#![no_std]
+#![no_main]
+
+use ed25519_dalek::{Signature, Verifier, VerifyingKey};
+use risc0_zkvm::guest::env;
+
+risc0_zkvm::entry!(main);
+
+const PUBLIC_KEY_LEN: usize = 32;
+const MESSAGE_LEN: usize = 64;
+const SIGNATURE_LEN: usize = 64;
+
+pub fn main() {
+    let data: &[u8] = env::send_recv(0, &[]);
+
+    let num_signatures = u32::from_le_bytes(data[0..4].try_into().unwrap());
+
+    // Each record is a public key, the message and the signature
+    let records = data[4..].chunks_exact(PUBLIC_KEY_LEN + MESSAGE_LEN + SIGNATURE_LEN);
+
+    // Every signature is checked, so a tampered batch costs as much to prove
+    // as a valid one
+    let valid = records
+        .take(num_signatures as usize)
+        .map(|record| {
+            let (public_key, rest) = record.split_at(PUBLIC_KEY_LEN);
+            let (message, signature) = rest.split_at(MESSAGE_LEN);
+
+            let signature = Signature::from_bytes(signature.try_into().unwrap());
+            match VerifyingKey::from_bytes(public_key.try_into().unwrap()) {
+                Ok(key) => key.verify(message, &signature).is_ok(),
+                Err(_) => false,
+            }
+        })
+        .fold(true, |valid, ok| valid & ok);
+
+    env::commit(&valid)
+}
@@ -0,0 +1,41 @@
+#![no_std]
+#![no_main]
+
+use risc0_zkvm::guest::env;
+use rlp::Rlp;
+use sha3::{Digest as _, Keccak256};
+
+risc0_zkvm::entry!(main);
+
+// Positions of the fields we check in the RLP list of a header
+const PARENT_HASH: usize = 0;
+const NUMBER: usize = 8;
+
+pub fn main() {
+    let data: &[u8] = env::send_recv(0, &[]);
+
+    let num_headers = u32::from_le_bytes(data[0..4].try_into().unwrap());
+
+    // The headers are RLP lists, one after the other. We keep the hash and the
+    // number of the previous header to check that the next one links to it.
+    let mut rest = &data[4..];
+    let mut parent: Option<([u8; 32], u64)> = None;
+    for _ in 0..num_headers {
+        let len = Rlp::new(rest).payload_info().unwrap().total();
+        let (raw, tail) = rest.split_at(len);
+        rest = tail;
+
+        let header = Rlp::new(raw);
+        let parent_hash = header.at(PARENT_HASH).unwrap().data().unwrap();
+        let number: u64 = header.val_at(NUMBER).unwrap();
+        if let Some((hash, parent_number)) = parent {
+            assert_eq!(parent_hash, &hash[..], "header does not link to its parent");
+            assert_eq!(number, parent_number + 1, "header number is not consecutive");
+        }
+
+        parent = Some((Keccak256::digest(raw).into(), number));
+    }
+
+    // The hash of the tip of the chain
+    env::commit_slice(&parent.unwrap().0);
+}
@@ -0,0 +1,58 @@
+#![no_std]
+#![no_main]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use risc0_zkvm::guest::env;
+use risc0_zkvm::{sha, sha::Sha256};
+
+risc0_zkvm::entry!(main);
+
+/// Crops an eighth of the width and the height off every side of an RGB image,
+/// converts it to 8-bit grayscale and halves both dimensions by averaging every
+/// 2×2 block. Width and height must be multiples of 16.
+fn transform(width: usize, height: usize, rgb: &[u8]) -> Vec<u8> {
+    // crop
+    let (left, top) = (width / 8, height / 8);
+    let (crop_width, crop_height) = (width - 2 * left, height - 2 * top);
+
+    // grayscale, with the BT.601 luma weights in 8-bit fixed point
+    let mut gray = Vec::with_capacity(crop_width * crop_height);
+    for y in top..top + crop_height {
+        for x in left..left + crop_width {
+            let p = &rgb[3 * (y * width + x)..][..3];
+            let luma = 77 * p[0] as u32 + 150 * p[1] as u32 + 29 * p[2] as u32;
+            gray.push((luma >> 8) as u8);
+        }
+    }
+
+    // resize
+    let (out_width, out_height) = (crop_width / 2, crop_height / 2);
+    let mut out = Vec::with_capacity(out_width * out_height);
+    for y in 0..out_height {
+        for x in 0..out_width {
+            let i = 2 * y * crop_width + 2 * x;
+            let sum = gray[i] as u32
+                + gray[i + 1] as u32
+                + gray[i + crop_width] as u32
+                + gray[i + crop_width + 1] as u32;
+            out.push((sum / 4) as u8);
+        }
+    }
+
+    out
+}
+
+pub fn main() {
+    let data: &[u8] = env::send_recv(0, &[]);
+
+    let width = u32::from_le_bytes(data[0..4].try_into().unwrap()) as usize;
+    let height = u32::from_le_bytes(data[4..8].try_into().unwrap()) as usize;
+    let rgb = &data[8..8 + 3 * width * height];
+
+    let output = transform(width, height, rgb);
+
+    env::commit(sha::Impl::hash_bytes(rgb));
+    env::commit(sha::Impl::hash_bytes(&output));
+}
@@ -0,0 +1,21 @@
+#![no_std]
+#![no_main]
+
+use risc0_zkvm::guest::env;
+use risc0_zkvm::sha::Digest;
+
+risc0_zkvm::entry!(main);
+
+pub fn main() {
+    let data: &[u8] = env::send_recv(0, &[]);
+
+    let num_iter = u32::from_le_bytes(data[0..4].try_into().unwrap());
+
+    // BLAKE3 has no accelerator, so this runs the portable implementation
+    let mut hash: [u8; 32] = data[4..36].try_into().unwrap();
+    for _ in 0..num_iter {
+        hash = *blake3::hash(&hash).as_bytes();
+    }
+
+    env::commit(&Digest::from(hash))
+}
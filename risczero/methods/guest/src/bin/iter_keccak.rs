@@ -0,0 +1,25 @@
+#![no_std]
+#![no_main]
+
+use risc0_zkvm::guest::env;
+use risc0_zkvm::sha::Digest;
+use sha3::{Digest as _, Keccak256};
+
+risc0_zkvm::entry!(main);
+
+pub fn main() {
+    let data: &[u8] = env::send_recv(0, &[]);
+
+    let num_iter = u32::from_le_bytes(data[0..4].try_into().unwrap());
+
+    // Keccak has no accelerator, so this runs the software implementation
+    let mut hash: [u8; 32] = data[4..36].try_into().unwrap();
+    for _ in 0..num_iter {
+        let mut hasher = Keccak256::new();
+        hasher.update(hash);
+        hasher.update(hash);
+        hash = hasher.finalize().into();
+    }
+
+    env::commit(&Digest::from(hash))
+}
@@ -0,0 +1,23 @@
+#![no_std]
+#![no_main]
+
+use risc0_zkvm::guest::env;
+use serde_json::Value;
+
+risc0_zkvm::entry!(main);
+
+pub fn main() {
+    let data: &[u8] = env::send_recv(0, &[]);
+
+    let path_len = u32::from_le_bytes(data[0..4].try_into().unwrap()) as usize;
+    let document_len = u32::from_le_bytes(data[4..8].try_into().unwrap()) as usize;
+    let (path, rest) = data[8..].split_at(path_len);
+    let path = core::str::from_utf8(path).unwrap();
+    let document = &rest[..document_len];
+
+    // The whole document is parsed into a tree before the field is looked up
+    let value: Value = serde_json::from_slice(document).unwrap();
+    let field = value.pointer(path).and_then(Value::as_str).unwrap();
+
+    env::commit(&field)
+}
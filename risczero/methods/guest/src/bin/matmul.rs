@@ -0,0 +1,31 @@
+#![no_std]
+#![no_main]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use risc0_zkvm::guest::env;
+use risc0_zkvm::{sha, sha::Sha256};
+
+risc0_zkvm::entry!(main);
+
+pub fn main() {
+    let n: u32 = env::read();
+    let n = n as usize;
+    let a: &[u32] = env::read_slice(n * n);
+    let b: &[u32] = env::read_slice(n * n);
+
+    // C = A * B in row-major order, hashed as little endian bytes
+    let mut c = Vec::with_capacity(n * n * 4);
+    for i in 0..n {
+        for j in 0..n {
+            let mut sum: u32 = 0;
+            for k in 0..n {
+                sum = sum.wrapping_add(a[i * n + k].wrapping_mul(b[k * n + j]));
+            }
+            c.extend_from_slice(&sum.to_le_bytes());
+        }
+    }
+
+    env::commit(sha::Impl::hash_bytes(&c))
+}
@@ -0,0 +1,39 @@
+#![no_std]
+#![no_main]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use risc0_zkvm::guest::env;
+use risc0_zkvm::{
+    sha,
+    sha::{Digest, Sha256},
+};
+
+risc0_zkvm::entry!(main);
+
+pub fn main() {
+    let data: &[u8] = env::send_recv(0, &[]);
+
+    let n = u32::from_le_bytes(data[0..4].try_into().unwrap()) as usize;
+    let mut nodes: Vec<Digest> = data[4..]
+        .chunks_exact(32)
+        .take(n)
+        .map(|leaf| Digest::try_from(leaf).unwrap())
+        .collect();
+
+    // Every level replaces the nodes in place with their parents, which are
+    // the SHA-256 hashes of the 64-byte concatenation of the children
+    let mut pair = [0u8; 64];
+    let mut m = n;
+    while m > 1 {
+        for i in 0..m / 2 {
+            pair[..32].copy_from_slice(nodes[2 * i].as_bytes());
+            pair[32..].copy_from_slice(nodes[2 * i + 1].as_bytes());
+            nodes[i] = *sha::Impl::hash_bytes(&pair);
+        }
+        m /= 2;
+    }
+
+    env::commit(&nodes[0])
+}
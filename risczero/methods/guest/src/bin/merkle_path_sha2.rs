@@ -0,0 +1,37 @@
+#![no_std]
+#![no_main]
+
+use risc0_zkvm::guest::env;
+use risc0_zkvm::{
+    sha,
+    sha::{Digest, Sha256},
+};
+
+risc0_zkvm::entry!(main);
+
+pub fn main() {
+    let depth: u32 = env::read();
+    let num_paths: u32 = env::read();
+    let root: Digest = env::read();
+
+    for _ in 0..num_paths {
+        let mut index: u64 = env::read();
+        let leaf: Digest = env::read();
+        let path: &[Digest] = env::read_slice(depth as usize);
+
+        // Hash up from the leaf; the index bits tell on which side the sibling is
+        let mut node = leaf;
+        for sibling in path {
+            node = if index & 1 == 0 {
+                *sha::Impl::hash_pair(&node, sibling)
+            } else {
+                *sha::Impl::hash_pair(sibling, &node)
+            };
+            index >>= 1;
+        }
+
+        assert_eq!(node, root, "invalid Merkle path");
+    }
+
+    env::commit(&root);
+}
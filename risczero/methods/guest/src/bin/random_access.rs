@@ -0,0 +1,36 @@
+#![no_std]
+#![no_main]
+
+use risc0_zkvm::guest::env;
+
+risc0_zkvm::entry!(main);
+
+const MAX_WORKING_SET: usize = 1 << 20;
+
+// A static buffer lives in zero-initialized memory, so the guest does not
+// spend cycles clearing it and only the touched pages are paid for.
+static mut MEMORY: [u32; MAX_WORKING_SET] = [0; MAX_WORKING_SET];
+
+const LCG_MUL: u32 = 1664525;
+const LCG_ADD: u32 = 1013904223;
+
+pub fn main() {
+    let num_ops: u32 = env::read();
+    let working_set: u32 = env::read();
+    let seed: u32 = env::read();
+    assert!(working_set.is_power_of_two() && working_set as usize <= MAX_WORKING_SET);
+
+    let memory = unsafe { &mut MEMORY[..working_set as usize] };
+    let shift = 32 - working_set.trailing_zeros();
+
+    let mut x = seed;
+    let mut sum: u32 = 0;
+    for _ in 0..num_ops {
+        x = x.wrapping_mul(LCG_MUL).wrapping_add(LCG_ADD);
+        let addr = (x >> shift) as usize;
+        sum = sum.wrapping_add(memory[addr]);
+        memory[addr] = memory[addr].wrapping_add(x);
+    }
+
+    env::commit(&sum)
+}
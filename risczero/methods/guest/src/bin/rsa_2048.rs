@@ -0,0 +1,46 @@
+#![no_std]
+#![no_main]
+
+use risc0_zkvm::guest::env;
+use risc0_zkvm::{sha, sha::Sha256 as _};
+use rsa::{BigUint, Pkcs1v15Sign, RsaPublicKey};
+use sha2::Sha256;
+
+risc0_zkvm::entry!(main);
+
+const MODULUS_LEN: usize = 256;
+const MESSAGE_LEN: usize = 64;
+const SIGNATURE_LEN: usize = 256;
+
+pub fn main() {
+    let data: &[u8] = env::send_recv(0, &[]);
+
+    let num_signatures = u32::from_le_bytes(data[0..4].try_into().unwrap());
+    let exponent = BigUint::from(u32::from_le_bytes(data[4..8].try_into().unwrap()));
+
+    // Each record is a big-endian modulus, the message and the signature
+    let records = data[8..].chunks_exact(MODULUS_LEN + MESSAGE_LEN + SIGNATURE_LEN);
+
+    let mut num_valid: u32 = 0;
+    for record in records.take(num_signatures as usize) {
+        let (modulus, rest) = record.split_at(MODULUS_LEN);
+        let (message, signature) = rest.split_at(MESSAGE_LEN);
+
+        // The message digest uses the SHA-256 accelerator, the modular
+        // exponentiation runs in software. The digest type only selects the
+        // DigestInfo prefix of the padding.
+        let digest = sha::Impl::hash_bytes(message);
+        let valid = match RsaPublicKey::new(BigUint::from_bytes_be(modulus), exponent.clone()) {
+            Ok(key) => key
+                .verify(Pkcs1v15Sign::new::<Sha256>(), digest.as_bytes(), signature)
+                .is_ok(),
+            Err(_) => false,
+        };
+
+        if valid {
+            num_valid += 1;
+        }
+    }
+
+    env::commit(&num_valid)
+}
@@ -0,0 +1,44 @@
+#![no_std]
+#![no_main]
+
+use risc0_zkvm::guest::env;
+use risc0_zkvm::{
+    sha,
+    sha::{Digest, Sha256},
+};
+
+risc0_zkvm::entry!(main);
+
+/// Hashes up from a leaf; the index bits tell on which side the sibling is.
+fn root_from_path(mut index: u64, leaf: &Digest, path: &[Digest]) -> Digest {
+    let mut node = *leaf;
+    for sibling in path {
+        node = if index & 1 == 0 {
+            *sha::Impl::hash_pair(&node, sibling)
+        } else {
+            *sha::Impl::hash_pair(sibling, &node)
+        };
+        index >>= 1;
+    }
+    node
+}
+
+pub fn main() {
+    let depth: u32 = env::read();
+    let num_updates: u32 = env::read();
+    let mut root: Digest = env::read();
+
+    for _ in 0..num_updates {
+        let index: u64 = env::read();
+        let old_leaf: Digest = env::read();
+        let new_leaf: Digest = env::read();
+        let path: &[Digest] = env::read_slice(depth as usize);
+
+        // The path opens the old leaf against the current root, and the same
+        // siblings give the root after the update
+        assert_eq!(root_from_path(index, &old_leaf, path), root, "invalid Merkle path");
+        root = root_from_path(index, &new_leaf, path);
+    }
+
+    env::commit(&root);
+}
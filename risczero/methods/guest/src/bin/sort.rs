@@ -0,0 +1,21 @@
+#![no_std]
+#![no_main]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use risc0_zkvm::guest::env;
+use risc0_zkvm::{sha, sha::Sha256};
+
+risc0_zkvm::entry!(main);
+
+pub fn main() {
+    let n: u32 = env::read();
+    let mut values: Vec<u32> = Vec::from(env::read_slice::<u32>(n as usize));
+
+    values.sort_unstable();
+
+    // The sorted values are hashed as little endian bytes
+    let bytes: Vec<u8> = values.iter().flat_map(|v| v.to_le_bytes()).collect();
+    env::commit(sha::Impl::hash_bytes(&bytes))
+}
@@ -0,0 +1,46 @@
+#![no_std]
+#![no_main]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use risc0_zkvm::guest::env;
+use risc0_zkvm::{sha, sha::Sha256};
+
+risc0_zkvm::entry!(main);
+
+/// Checks that the solution fills in the puzzle and that every row, column
+/// and box holds each digit from 1 to 9 once.
+fn check(puzzle: &[u8], solution: &[u8]) {
+    for (p, s) in puzzle.iter().zip(solution) {
+        assert!((1..=9).contains(s), "not a digit");
+        assert!(*p == 0 || p == s, "does not match the puzzle");
+    }
+
+    for g in 0..9 {
+        let (mut row, mut col, mut square) = (0u16, 0u16, 0u16);
+        for i in 0..9 {
+            row |= 1 << solution[9 * g + i];
+            col |= 1 << solution[9 * i + g];
+            square |= 1 << solution[9 * (3 * (g / 3) + i / 3) + 3 * (g % 3) + i % 3];
+        }
+        assert!(row == 0x3fe && col == 0x3fe && square == 0x3fe, "repeated digit");
+    }
+}
+
+pub fn main() {
+    let data: &[u8] = env::send_recv(0, &[]);
+
+    let num_puzzles = u32::from_le_bytes(data[0..4].try_into().unwrap());
+
+    // Each record is the puzzle followed by its solution, one byte per cell.
+    // Only the hash of the puzzles leaves the guest.
+    let mut puzzles = Vec::with_capacity(81 * num_puzzles as usize);
+    for record in data[4..].chunks_exact(2 * 81).take(num_puzzles as usize) {
+        let (puzzle, solution) = record.split_at(81);
+        check(puzzle, solution);
+        puzzles.extend_from_slice(puzzle);
+    }
+
+    env::commit(sha::Impl::hash_bytes(&puzzles))
+}
@@ -0,0 +1,29 @@
+#![no_std]
+#![no_main]
+
+use crypto_bigint::modular::runtime_mod::{DynResidue, DynResidueParams};
+use crypto_bigint::U256;
+use risc0_zkvm::guest::env;
+
+risc0_zkvm::entry!(main);
+
+pub fn main() {
+    let data: &[u8] = env::send_recv(0, &[]);
+
+    let n = u32::from_le_bytes(data[0..4].try_into().unwrap());
+    let modulus = U256::from_le_slice(&data[4..36]);
+    let a = U256::from_le_slice(&data[36..68]);
+    let b = U256::from_le_slice(&data[68..100]);
+
+    // Montgomery form, so every multiplication is a multiply and a reduction
+    let params = DynResidueParams::new(&modulus);
+    let b = DynResidue::new(&b, params);
+    let mut x = DynResidue::new(&a, params);
+    for _ in 0..n {
+        x *= b;
+    }
+
+    // The limbs are 32 bits wide on the guest, least significant first
+    let limbs: [u32; 8] = x.retrieve().to_words();
+    env::commit(&limbs);
+}
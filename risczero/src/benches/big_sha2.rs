@@ -1,11 +1,13 @@
 use rand::{rngs::StdRng, RngCore, SeedableRng};
 use risc0_zkvm::sha::{Digest, DIGEST_WORDS};
-use risc0_zkvm::{Prover, Receipt};
+use risc0_zkvm::Receipt;
 use rustbench::Benchmark;
 
+use super::{receipt_breakdown, Guest};
+
 pub struct Job {
     pub guest_input: Vec<u8>,
-    pub prover: Prover<'static>,
+    pub guest: Guest,
 }
 
 pub fn new_jobs() -> Vec<<Job as Benchmark>::Spec> {
@@ -43,23 +45,28 @@ impl Benchmark for Job {
         proof.get_seal_bytes().len() as u32
     }
 
+    fn proof_size_breakdown(proof: &Self::ProofType) -> Vec<(&'static str, u32)> {
+        receipt_breakdown(proof)
+    }
+
     fn new(guest_input: Self::Spec) -> Self {
         let image = std::fs::read(METHOD_PATH).expect("image");
-        let mut prover = Prover::new(&image, METHOD_ID).expect("prover");
-        prover.add_input_u8_slice(guest_input.as_slice());
+        let mut guest = Guest::new(image, METHOD_ID);
+        guest.add_input_u8_slice(guest_input.as_slice());
 
-        Job {
-            guest_input,
-            prover,
-        }
+        Job { guest_input, guest }
     }
 
     fn spec(&self) -> &Self::Spec {
         &self.guest_input
     }
 
+    fn guest_setup(&mut self) {
+        self.guest.setup();
+    }
+
     fn guest_compute(&mut self) -> (Self::ComputeOut, Self::ProofType) {
-        let receipt = self.prover.run().expect("receipt");
+        let receipt = self.guest.prove();
 
         let journal = receipt.get_journal_bytes();
         let guest_output: Digest = Digest::try_from(journal).unwrap();
@@ -0,0 +1,134 @@
+use k256::ecdsa::{
+    signature::{hazmat::PrehashSigner, Verifier},
+    Signature, SigningKey, VerifyingKey,
+};
+use rand::{rngs::StdRng, RngCore, SeedableRng};
+use risc0_zkvm::serde::from_slice;
+use risc0_zkvm::sha::DIGEST_WORDS;
+use risc0_zkvm::Receipt;
+use rustbench::Benchmark;
+use sha2::{Digest, Sha256};
+
+use super::{receipt_breakdown, Guest};
+
+pub struct Spec {
+    pub signatures: Vec<(VerifyingKey, [u8; 64], Signature)>,
+}
+
+/// Verifies a number of secp256k1 ECDSA signatures over random 64-byte
+/// messages and commits the count of valid signatures.
+pub struct Job {
+    pub spec: Spec,
+    pub guest: Guest,
+}
+
+pub fn new_jobs() -> Vec<<Job as Benchmark>::Spec> {
+    // Signing is fixture work, not setup, so it stays out of setup_duration.
+    // Every job takes a prefix of the same signatures.
+    let signatures = random_signatures(3);
+    [1, 2, 3]
+        .into_iter()
+        .map(|n| Spec {
+            signatures: signatures[..n].to_vec(),
+        })
+        .collect()
+}
+
+/// Signs `n` random messages, each with a fresh key. We sign the SHA-256
+/// digest of the message, which is what the guest verifies.
+fn random_signatures(n: usize) -> Vec<(VerifyingKey, [u8; 64], Signature)> {
+    let mut rand = StdRng::seed_from_u64(1337);
+    let mut signatures = Vec::new();
+    for _ in 0..n {
+        let signing_key = SigningKey::random(&mut rand);
+        let mut message = [0u8; 64];
+        rand.fill_bytes(&mut message);
+        let signature: Signature = signing_key
+            .sign_prehash(&Sha256::digest(message))
+            .expect("signature");
+        signatures.push((*signing_key.verifying_key(), message, signature));
+    }
+    signatures
+}
+
+const METHOD_ID: [u32; DIGEST_WORDS] = risczero_benchmark_methods::ECDSA_SECP256K1_ID;
+const METHOD_PATH: &'static str = risczero_benchmark_methods::ECDSA_SECP256K1_PATH;
+
+impl Benchmark for Job {
+    const NAME: &'static str = "ecdsa_secp256k1";
+    type Spec = Spec;
+    type ComputeOut = u32;
+    type ProofType = Receipt;
+
+    fn job_size(spec: &Self::Spec) -> u32 {
+        spec.signatures.len() as u32
+    }
+
+    fn output_size_bytes(_output: &Self::ComputeOut, proof: &Self::ProofType) -> u32 {
+        proof.get_journal_bytes().len() as u32
+    }
+
+    fn proof_size_bytes(proof: &Self::ProofType) -> u32 {
+        proof.get_seal_bytes().len() as u32
+    }
+
+    fn proof_size_breakdown(proof: &Self::ProofType) -> Vec<(&'static str, u32)> {
+        receipt_breakdown(proof)
+    }
+
+    fn new(spec: Self::Spec) -> Self {
+        // guest input: [num_signatures, (public_key, message, signature)...]
+        let mut guest_input = Vec::from(Self::job_size(&spec).to_le_bytes());
+        for (verifying_key, message, signature) in spec.signatures.iter() {
+            guest_input.extend_from_slice(verifying_key.to_encoded_point(true).as_bytes());
+            guest_input.extend_from_slice(message);
+            guest_input.extend_from_slice(&signature.to_bytes());
+        }
+
+        let image = std::fs::read(METHOD_PATH).expect("image");
+        let mut guest = Guest::new(image, METHOD_ID);
+        guest.add_input_u8_slice(guest_input.as_slice());
+
+        Job { spec, guest }
+    }
+
+    fn spec(&self) -> &Self::Spec {
+        &self.spec
+    }
+
+    fn host_compute(&mut self) -> Option<Self::ComputeOut> {
+        let num_valid = self
+            .spec
+            .signatures
+            .iter()
+            .filter(|(verifying_key, message, signature)| {
+                verifying_key.verify(message, signature).is_ok()
+            })
+            .count();
+
+        Some(num_valid as u32)
+    }
+
+    fn guest_setup(&mut self) {
+        self.guest.setup();
+    }
+
+    fn guest_compute(&mut self) -> (Self::ComputeOut, Self::ProofType) {
+        let receipt = self.guest.prove();
+
+        let num_valid: u32 = from_slice(&receipt.journal).unwrap();
+        (num_valid, receipt)
+    }
+
+    fn verify_proof(&self, _output: &Self::ComputeOut, proof: &Self::ProofType) -> bool {
+        let result = proof.verify(&METHOD_ID);
+
+        match result {
+            Ok(_) => true,
+            Err(err) => {
+                println!("{}", err);
+                false
+            }
+        }
+    }
+}
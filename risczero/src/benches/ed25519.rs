@@ -0,0 +1,144 @@
+use ed25519_dalek::{Signature, Signer, SigningKey, Verifier, VerifyingKey};
+use rand::{rngs::StdRng, Rng, RngCore, SeedableRng};
+use risc0_zkvm::serde::from_slice;
+use risc0_zkvm::sha::DIGEST_WORDS;
+use risc0_zkvm::Receipt;
+use rustbench::Benchmark;
+
+use super::{receipt_breakdown, Guest};
+
+/// Verifies a batch of Ed25519 signatures over random 64-byte messages and
+/// commits whether all of them are valid. In the tampered variant one
+/// signature of the batch is corrupted, so the guest must commit `false`.
+pub struct Spec {
+    pub tampered: bool,
+    pub signatures: Vec<(VerifyingKey, [u8; 64], Signature)>,
+}
+
+pub struct Job {
+    pub spec: Spec,
+    pub guest: Guest,
+}
+
+pub fn new_jobs() -> Vec<<Job as Benchmark>::Spec> {
+    // Every signature is made with a fresh key over a random message. Keys are
+    // made once, up front, and each batch is a prefix of them.
+    let mut rand = StdRng::seed_from_u64(1337);
+    let mut signatures = Vec::new();
+    for _ in 0..5 {
+        let signing_key = SigningKey::generate(&mut rand);
+        let mut message = [0u8; 64];
+        rand.fill_bytes(&mut message);
+        let signature = signing_key.sign(&message);
+        signatures.push((signing_key.verifying_key(), message, signature));
+    }
+
+    let mut jobs = Vec::new();
+    for tampered in [false, true] {
+        for num_signatures in [1, 2, 5] {
+            let mut batch = signatures[..num_signatures].to_vec();
+
+            // Flip one bit of a random signature
+            if tampered {
+                let index = rand.gen_range(0..batch.len());
+                let mut bytes = batch[index].2.to_bytes();
+                bytes[rand.gen_range(0..bytes.len())] ^= 1;
+                batch[index].2 = Signature::from_bytes(&bytes);
+            }
+
+            jobs.push(Spec {
+                tampered,
+                signatures: batch,
+            });
+        }
+    }
+    jobs
+}
+
+const METHOD_ID: [u32; DIGEST_WORDS] = risczero_benchmark_methods::ED25519_ID;
+const METHOD_PATH: &'static str = risczero_benchmark_methods::ED25519_PATH;
+
+impl Benchmark for Job {
+    const NAME: &'static str = "ed25519";
+    type Spec = Spec;
+    type ComputeOut = bool;
+    type ProofType = Receipt;
+
+    fn job_size(spec: &Self::Spec) -> u32 {
+        spec.signatures.len() as u32
+    }
+
+    fn output_size_bytes(_output: &Self::ComputeOut, proof: &Self::ProofType) -> u32 {
+        proof.get_journal_bytes().len() as u32
+    }
+
+    fn proof_size_bytes(proof: &Self::ProofType) -> u32 {
+        proof.get_seal_bytes().len() as u32
+    }
+
+    fn proof_size_breakdown(proof: &Self::ProofType) -> Vec<(&'static str, u32)> {
+        receipt_breakdown(proof)
+    }
+
+    fn new(spec: Self::Spec) -> Self {
+        // guest input: [num_signatures, (public_key, message, signature)...]
+        let mut guest_input = Vec::from(Self::job_size(&spec).to_le_bytes());
+        for (verifying_key, message, signature) in spec.signatures.iter() {
+            guest_input.extend_from_slice(verifying_key.as_bytes());
+            guest_input.extend_from_slice(message);
+            guest_input.extend_from_slice(&signature.to_bytes());
+        }
+
+        let image = std::fs::read(METHOD_PATH).expect("image");
+        let mut guest = Guest::new(image, METHOD_ID);
+        guest.add_input_u8_slice(guest_input.as_slice());
+
+        Job { spec, guest }
+    }
+
+    fn spec(&self) -> &Self::Spec {
+        &self.spec
+    }
+
+    fn job_params(&self) -> String {
+        format!("tampered={}", self.spec.tampered)
+    }
+
+    fn host_compute(&mut self) -> Option<Self::ComputeOut> {
+        let valid = self
+            .spec
+            .signatures
+            .iter()
+            .all(|(verifying_key, message, signature)| {
+                verifying_key.verify(message, signature).is_ok()
+            });
+
+        // A tampered batch that still verifies would make the variant meaningless
+        assert_eq!(valid, !self.spec.tampered);
+
+        Some(valid)
+    }
+
+    fn guest_setup(&mut self) {
+        self.guest.setup();
+    }
+
+    fn guest_compute(&mut self) -> (Self::ComputeOut, Self::ProofType) {
+        let receipt = self.guest.prove();
+
+        let valid: bool = from_slice(&receipt.journal).unwrap();
+        (valid, receipt)
+    }
+
+    fn verify_proof(&self, _output: &Self::ComputeOut, proof: &Self::ProofType) -> bool {
+        let result = proof.verify(&METHOD_ID);
+
+        match result {
+            Ok(_) => true,
+            Err(err) => {
+                println!("{}", err);
+                false
+            }
+        }
+    }
+}
@@ -0,0 +1,130 @@
+use risc0_zkvm::sha::DIGEST_WORDS;
+use risc0_zkvm::Receipt;
+use rlp::Rlp;
+use rustbench::Benchmark;
+use sha3::{Digest as _, Keccak256};
+
+use super::{receipt_breakdown, Guest};
+
+/// Decodes a chain of `spec` RLP-encoded Ethereum block headers, checks that
+/// every header's `parent_hash` is the Keccak-256 hash of the previous header
+/// and commits the hash of the last one. The headers are synthetic and come
+/// from `fixtures/eth_headers.rlp`, see `fixtures/gen_eth_headers.py`.
+pub struct Job {
+    pub spec: u32,
+    pub guest: Guest,
+    pub headers: Vec<&'static [u8]>,
+}
+
+pub fn new_jobs() -> Vec<<Job as Benchmark>::Spec> {
+    vec![1, 10, 100]
+}
+
+static HEADERS: &[u8] = include_bytes!("../../fixtures/eth_headers.rlp");
+
+// Positions of the fields we check in the RLP list of a header
+const PARENT_HASH: usize = 0;
+const NUMBER: usize = 8;
+
+const METHOD_ID: [u32; DIGEST_WORDS] = risczero_benchmark_methods::ETH_HEADERS_ID;
+const METHOD_PATH: &'static str = risczero_benchmark_methods::ETH_HEADERS_PATH;
+
+/// Splits the first `n` RLP items off the fixture.
+fn first_headers(n: usize) -> Vec<&'static [u8]> {
+    let mut rest = HEADERS;
+    let mut headers = Vec::new();
+    for _ in 0..n {
+        let len = Rlp::new(rest).payload_info().expect("header").total();
+        let (header, tail) = rest.split_at(len);
+        headers.push(header);
+        rest = tail;
+    }
+    headers
+}
+
+impl Benchmark for Job {
+    const NAME: &'static str = "eth_headers";
+    type Spec = u32;
+    type ComputeOut = [u8; 32];
+    type ProofType = Receipt;
+
+    fn job_size(spec: &Self::Spec) -> u32 {
+        *spec
+    }
+
+    fn output_size_bytes(_output: &Self::ComputeOut, proof: &Self::ProofType) -> u32 {
+        proof.get_journal_bytes().len() as u32
+    }
+
+    fn proof_size_bytes(proof: &Self::ProofType) -> u32 {
+        proof.get_seal_bytes().len() as u32
+    }
+
+    fn proof_size_breakdown(proof: &Self::ProofType) -> Vec<(&'static str, u32)> {
+        receipt_breakdown(proof)
+    }
+
+    fn new(spec: Self::Spec) -> Self {
+        let headers = first_headers(spec as usize);
+
+        // guest input: [num_headers, headers...]
+        let mut guest_input = Vec::from(spec.to_le_bytes());
+        for header in headers.iter() {
+            guest_input.extend_from_slice(header);
+        }
+
+        let image = std::fs::read(METHOD_PATH).expect("image");
+        let mut guest = Guest::new(image, METHOD_ID);
+        guest.add_input_u8_slice(guest_input.as_slice());
+
+        Job {
+            spec,
+            guest,
+            headers,
+        }
+    }
+
+    fn spec(&self) -> &Self::Spec {
+        &self.spec
+    }
+
+    fn host_compute(&mut self) -> Option<Self::ComputeOut> {
+        let mut parent: Option<([u8; 32], u64)> = None;
+        for raw in self.headers.iter() {
+            let header = Rlp::new(raw);
+            let parent_hash = header.at(PARENT_HASH).unwrap().data().unwrap();
+            let number: u64 = header.val_at(NUMBER).unwrap();
+            if let Some((hash, parent_number)) = parent {
+                assert_eq!(parent_hash, &hash[..]);
+                assert_eq!(number, parent_number + 1);
+            }
+
+            parent = Some((Keccak256::digest(raw).into(), number));
+        }
+
+        Some(parent.unwrap().0)
+    }
+
+    fn guest_setup(&mut self) {
+        self.guest.setup();
+    }
+
+    fn guest_compute(&mut self) -> (Self::ComputeOut, Self::ProofType) {
+        let receipt = self.guest.prove();
+
+        let tip_hash = <[u8; 32]>::try_from(receipt.get_journal_bytes()).unwrap();
+        (tip_hash, receipt)
+    }
+
+    fn verify_proof(&self, _output: &Self::ComputeOut, proof: &Self::ProofType) -> bool {
+        let result = proof.verify(&METHOD_ID);
+
+        match result {
+            Ok(_) => true,
+            Err(err) => {
+                println!("{}", err);
+                false
+            }
+        }
+    }
+}
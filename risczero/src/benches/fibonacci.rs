@@ -0,0 +1,89 @@
+use risc0_zkvm::serde::{from_slice, to_vec};
+use risc0_zkvm::sha::DIGEST_WORDS;
+use risc0_zkvm::Receipt;
+use rustbench::Benchmark;
+
+use super::{receipt_breakdown, Guest};
+
+/// Computes the n-th Fibonacci number with wrapping u64 arithmetic, as a
+/// baseline for the proving cost per instruction without any accelerator.
+pub struct Job {
+    pub spec: u32,
+    pub guest: Guest,
+}
+
+pub fn new_jobs() -> Vec<<Job as Benchmark>::Spec> {
+    vec![1000, 10_000, 100_000, 1_000_000]
+}
+
+const METHOD_ID: [u32; DIGEST_WORDS] = risczero_benchmark_methods::FIBONACCI_ID;
+const METHOD_PATH: &'static str = risczero_benchmark_methods::FIBONACCI_PATH;
+
+impl Benchmark for Job {
+    const NAME: &'static str = "fibonacci";
+    type Spec = u32;
+    type ComputeOut = u64;
+    type ProofType = Receipt;
+
+    fn job_size(spec: &Self::Spec) -> u32 {
+        *spec
+    }
+
+    fn output_size_bytes(_output: &Self::ComputeOut, proof: &Self::ProofType) -> u32 {
+        proof.get_journal_bytes().len() as u32
+    }
+
+    fn proof_size_bytes(proof: &Self::ProofType) -> u32 {
+        proof.get_seal_bytes().len() as u32
+    }
+
+    fn proof_size_breakdown(proof: &Self::ProofType) -> Vec<(&'static str, u32)> {
+        receipt_breakdown(proof)
+    }
+
+    fn new(spec: Self::Spec) -> Self {
+        let image = std::fs::read(METHOD_PATH).expect("image");
+        let mut guest = Guest::new(image, METHOD_ID);
+        guest.add_input_u32_slice(&to_vec(&spec).unwrap());
+
+        Job { spec, guest }
+    }
+
+    fn spec(&self) -> &Self::Spec {
+        &self.spec
+    }
+
+    fn host_compute(&mut self) -> Option<Self::ComputeOut> {
+        let mut a: u64 = 0;
+        let mut b: u64 = 1;
+
+        for _ in 0..self.spec {
+            (a, b) = (b, a.wrapping_add(b));
+        }
+
+        Some(b)
+    }
+
+    fn guest_setup(&mut self) {
+        self.guest.setup();
+    }
+
+    fn guest_compute(&mut self) -> (Self::ComputeOut, Self::ProofType) {
+        let receipt = self.guest.prove();
+
+        let result: u64 = from_slice(&receipt.journal).unwrap();
+        (result, receipt)
+    }
+
+    fn verify_proof(&self, _output: &Self::ComputeOut, proof: &Self::ProofType) -> bool {
+        let result = proof.verify(&METHOD_ID);
+
+        match result {
+            Ok(_) => true,
+            Err(err) => {
+                println!("{}", err);
+                false
+            }
+        }
+    }
+}
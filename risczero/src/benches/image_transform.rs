@@ -0,0 +1,134 @@
+use rand::{rngs::StdRng, RngCore, SeedableRng};
+use risc0_zkvm::sha::{Digest, DIGEST_WORDS};
+use risc0_zkvm::Receipt;
+use rustbench::Benchmark;
+use sha2::{Digest as _, Sha256};
+
+use super::{receipt_breakdown, Guest};
+
+/// Crops, grayscales and downsizes a random square RGB image with `spec`
+/// pixels per side and commits the SHA-256 hashes of the source image and of
+/// the result, so a verifier learns which image was transformed into which.
+/// The job size is the number of pixels of the source image.
+pub struct Job {
+    pub spec: u32,
+    pub guest: Guest,
+    pub rgb: Vec<u8>,
+}
+
+pub fn new_jobs() -> Vec<<Job as Benchmark>::Spec> {
+    vec![64, 128, 256, 512]
+}
+
+const METHOD_ID: [u32; DIGEST_WORDS] = risczero_benchmark_methods::IMAGE_TRANSFORM_ID;
+const METHOD_PATH: &'static str = risczero_benchmark_methods::IMAGE_TRANSFORM_PATH;
+
+/// The same transformation as the guest: crops an eighth of the width and the
+/// height off every side, converts to grayscale and averages every 2×2 block.
+fn transform(width: usize, height: usize, rgb: &[u8]) -> Vec<u8> {
+    let (left, top) = (width / 8, height / 8);
+    let (crop_width, crop_height) = (width - 2 * left, height - 2 * top);
+
+    let gray: Vec<u8> = (top..top + crop_height)
+        .flat_map(|y| (left..left + crop_width).map(move |x| y * width + x))
+        .map(|i| {
+            let p = &rgb[3 * i..][..3];
+            let luma = 77 * p[0] as u32 + 150 * p[1] as u32 + 29 * p[2] as u32;
+            (luma >> 8) as u8
+        })
+        .collect();
+
+    (0..crop_height / 2)
+        .flat_map(|y| (0..crop_width / 2).map(move |x| 2 * y * crop_width + 2 * x))
+        .map(|i| {
+            let sum = gray[i] as u32
+                + gray[i + 1] as u32
+                + gray[i + crop_width] as u32
+                + gray[i + crop_width + 1] as u32;
+            (sum / 4) as u8
+        })
+        .collect()
+}
+
+impl Benchmark for Job {
+    const NAME: &'static str = "image_transform";
+    type Spec = u32;
+    type ComputeOut = (Digest, Digest);
+    type ProofType = Receipt;
+
+    fn job_size(spec: &Self::Spec) -> u32 {
+        spec * spec
+    }
+
+    fn output_size_bytes(_output: &Self::ComputeOut, proof: &Self::ProofType) -> u32 {
+        proof.get_journal_bytes().len() as u32
+    }
+
+    fn proof_size_bytes(proof: &Self::ProofType) -> u32 {
+        proof.get_seal_bytes().len() as u32
+    }
+
+    fn proof_size_breakdown(proof: &Self::ProofType) -> Vec<(&'static str, u32)> {
+        receipt_breakdown(proof)
+    }
+
+    fn new(spec: Self::Spec) -> Self {
+        let mut rand = StdRng::seed_from_u64(1337);
+        let mut rgb = vec![0u8; 3 * (spec * spec) as usize];
+        rand.fill_bytes(&mut rgb);
+
+        // guest input: [width, height, rgb]
+        let mut guest_input = Vec::from(spec.to_le_bytes());
+        guest_input.extend_from_slice(&spec.to_le_bytes());
+        guest_input.extend_from_slice(&rgb);
+
+        let image = std::fs::read(METHOD_PATH).expect("image");
+        let mut guest = Guest::new(image, METHOD_ID);
+        guest.add_input_u8_slice(guest_input.as_slice());
+
+        Job { spec, guest, rgb }
+    }
+
+    fn spec(&self) -> &Self::Spec {
+        &self.spec
+    }
+
+    fn job_params(&self) -> String {
+        format!("{0}x{0}", self.spec)
+    }
+
+    fn host_compute(&mut self) -> Option<Self::ComputeOut> {
+        let side = self.spec as usize;
+        let output = transform(side, side, &self.rgb);
+
+        let source_hash = Digest::try_from(&Sha256::digest(&self.rgb)[..]).unwrap();
+        let output_hash = Digest::try_from(&Sha256::digest(output)[..]).unwrap();
+        Some((source_hash, output_hash))
+    }
+
+    fn guest_setup(&mut self) {
+        self.guest.setup();
+    }
+
+    fn guest_compute(&mut self) -> (Self::ComputeOut, Self::ProofType) {
+        let receipt = self.guest.prove();
+
+        // The journal holds the source hash followed by the output hash
+        let journal = receipt.get_journal_bytes();
+        let source_hash = Digest::try_from(&journal[..32]).unwrap();
+        let output_hash = Digest::try_from(&journal[32..]).unwrap();
+        ((source_hash, output_hash), receipt)
+    }
+
+    fn verify_proof(&self, _output: &Self::ComputeOut, proof: &Self::ProofType) -> bool {
+        let result = proof.verify(&METHOD_ID);
+
+        match result {
+            Ok(_) => true,
+            Err(err) => {
+                println!("{}", err);
+                false
+            }
+        }
+    }
+}
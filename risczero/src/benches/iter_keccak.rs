@@ -0,0 +1,94 @@
+use risc0_zkvm::sha::{Digest, DIGEST_WORDS};
+use risc0_zkvm::Receipt;
+use rustbench::Benchmark;
+use sha3::{Digest as _, Keccak256};
+
+use super::{receipt_breakdown, Guest};
+
+/// Iterated Keccak-256 where every step hashes the previous digest concatenated with
+/// itself, matching the 2-to-1 hash used by the Miden benchmark.
+pub struct Job {
+    pub spec: u32,
+    pub guest: Guest,
+}
+
+pub fn new_jobs() -> Vec<<Job as Benchmark>::Spec> {
+    vec![1, 10, 100]
+}
+
+const METHOD_ID: [u32; DIGEST_WORDS] = risczero_benchmark_methods::ITER_KECCAK_ID;
+const METHOD_PATH: &'static str = risczero_benchmark_methods::ITER_KECCAK_PATH;
+
+impl Benchmark for Job {
+    const NAME: &'static str = "iter_keccak";
+    type Spec = u32;
+    type ComputeOut = Digest;
+    type ProofType = Receipt;
+
+    fn job_size(spec: &Self::Spec) -> u32 {
+        *spec
+    }
+
+    fn output_size_bytes(_output: &Self::ComputeOut, proof: &Self::ProofType) -> u32 {
+        (proof.get_journal_bytes().len()) as u32
+    }
+
+    fn proof_size_bytes(proof: &Self::ProofType) -> u32 {
+        (proof.get_seal_bytes().len()) as u32
+    }
+
+    fn proof_size_breakdown(proof: &Self::ProofType) -> Vec<(&'static str, u32)> {
+        receipt_breakdown(proof)
+    }
+
+    fn new(spec: Self::Spec) -> Self {
+        let image = std::fs::read(METHOD_PATH).expect("image");
+        let mut guest = Guest::new(image, METHOD_ID);
+
+        let mut guest_input = Vec::from([0u8; 36]);
+        guest_input[0..4].copy_from_slice(&spec.to_le_bytes());
+        guest.add_input_u8_slice(guest_input.as_slice());
+
+        Job { spec, guest }
+    }
+
+    fn spec(&self) -> &Self::Spec {
+        &self.spec
+    }
+
+    fn host_compute(&mut self) -> Option<Self::ComputeOut> {
+        let mut data = [0u8; 32];
+
+        for _i in 0..self.spec {
+            let mut hasher = Keccak256::new();
+            hasher.update(data);
+            hasher.update(data);
+            data = hasher.finalize().into();
+        }
+
+        Some(Digest::from(data))
+    }
+
+    fn guest_setup(&mut self) {
+        self.guest.setup();
+    }
+
+    fn guest_compute(&mut self) -> (Self::ComputeOut, Self::ProofType) {
+        let receipt = self.guest.prove();
+
+        let result = Digest::try_from(receipt.journal.as_slice()).unwrap();
+        (result, receipt)
+    }
+
+    fn verify_proof(&self, _output: &Self::ComputeOut, proof: &Self::ProofType) -> bool {
+        let result = proof.verify(&METHOD_ID);
+
+        match result {
+            Ok(_) => true,
+            Err(err) => {
+                println!("{}", err);
+                false
+            }
+        }
+    }
+}
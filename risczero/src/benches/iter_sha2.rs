@@ -1,11 +1,13 @@
 use risc0_zkvm::sha::DIGEST_WORDS;
-use risc0_zkvm::{Prover, Receipt};
+use risc0_zkvm::Receipt;
 use rustbench::Benchmark;
 use sha2::{Digest, Sha256};
 
+use super::{receipt_breakdown, Guest};
+
 pub struct Job {
     pub spec: u32,
-    pub prover: Prover<'static>,
+    pub guest: Guest,
 }
 
 pub fn new_jobs() -> Vec<<Job as Benchmark>::Spec> {
@@ -33,18 +35,22 @@ impl Benchmark for Job {
         (proof.get_seal_bytes().len()) as u32
     }
 
+    fn proof_size_breakdown(proof: &Self::ProofType) -> Vec<(&'static str, u32)> {
+        receipt_breakdown(proof)
+    }
+
     fn new(spec: Self::Spec) -> Self {
         let image = std::fs::read(METHOD_PATH).expect("image");
-        let mut prover = Prover::new(&image, METHOD_ID).expect("prover");
+        let mut guest = Guest::new(image, METHOD_ID);
 
         let mut guest_input = Vec::from([0u8; 36]);
         guest_input[0] = spec as u8;
         guest_input[1] = (spec >> 8) as u8;
         guest_input[2] = (spec >> 16) as u8;
         guest_input[3] = (spec >> 24) as u8;
-        prover.add_input_u8_slice(guest_input.as_slice());
+        guest.add_input_u8_slice(guest_input.as_slice());
 
-        Job { spec, prover }
+        Job { spec, guest }
     }
 
     fn spec(&self) -> &Self::Spec {
@@ -63,8 +69,12 @@ impl Benchmark for Job {
         Some(risc0_zkvm::sha::Digest::try_from(data.as_slice()).unwrap())
     }
 
+    fn guest_setup(&mut self) {
+        self.guest.setup();
+    }
+
     fn guest_compute(&mut self) -> (Self::ComputeOut, Self::ProofType) {
-        let receipt = self.prover.run().expect("receipt");
+        let receipt = self.guest.prove();
 
         let result = risc0_zkvm::sha::Digest::try_from(receipt.journal.as_slice()).unwrap();
         (result, receipt)
@@ -0,0 +1,153 @@
+use rand::{distributions::Alphanumeric, rngs::StdRng, Rng, SeedableRng};
+use risc0_zkvm::serde::from_slice;
+use risc0_zkvm::sha::DIGEST_WORDS;
+use risc0_zkvm::Receipt;
+use rustbench::Benchmark;
+use serde_json::{json, Value};
+
+use super::{receipt_breakdown, Guest};
+
+/// Parses a JSON document of `spec` bytes with serde_json and commits the
+/// string at a JSON pointer path. The document is an array of random records
+/// and the path points at the name of the last record.
+pub struct Job {
+    pub spec: u32,
+    pub guest: Guest,
+    pub document: Vec<u8>,
+    pub path: String,
+}
+
+pub fn new_jobs() -> Vec<<Job as Benchmark>::Spec> {
+    vec![1024, 4096, 16384, 65536]
+}
+
+/// A random record of roughly 100 bytes.
+fn random_record(rand: &mut StdRng, id: usize) -> Value {
+    let mut word = |len| -> String {
+        rand.sample_iter(&Alphanumeric)
+            .take(len)
+            .map(char::from)
+            .collect()
+    };
+    let name = word(12);
+    let tags: Vec<String> = (0..3).map(|_| word(6)).collect();
+
+    json!({
+        "id": id,
+        "name": name,
+        "price": rand.gen_range(0.0..1000.0),
+        "active": rand.gen::<bool>(),
+        "tags": tags,
+    })
+}
+
+/// Builds a document of exactly `size` bytes with as many records as fit and
+/// whitespace padding, and returns it with the path to the last record's name.
+fn random_document(size: usize) -> (Vec<u8>, String) {
+    let mut rand = StdRng::seed_from_u64(1337);
+    let wrap = |records: &[String]| format!("{{\"records\":[{}]}}", records.join(","));
+
+    let mut records = Vec::new();
+    loop {
+        records.push(random_record(&mut rand, records.len()).to_string());
+        if wrap(&records).len() > size {
+            records.pop();
+            break;
+        }
+    }
+    assert!(
+        !records.is_empty(),
+        "document too small for a single record"
+    );
+
+    let mut document = wrap(&records);
+    document.push_str(&" ".repeat(size - document.len()));
+
+    let path = format!("/records/{}/name", records.len() - 1);
+    (document.into_bytes(), path)
+}
+
+const METHOD_ID: [u32; DIGEST_WORDS] = risczero_benchmark_methods::JSON_PARSE_ID;
+const METHOD_PATH: &'static str = risczero_benchmark_methods::JSON_PARSE_PATH;
+
+impl Benchmark for Job {
+    const NAME: &'static str = "json_parse";
+    type Spec = u32;
+    type ComputeOut = String;
+    type ProofType = Receipt;
+
+    fn job_size(spec: &Self::Spec) -> u32 {
+        *spec
+    }
+
+    fn output_size_bytes(_output: &Self::ComputeOut, proof: &Self::ProofType) -> u32 {
+        proof.get_journal_bytes().len() as u32
+    }
+
+    fn proof_size_bytes(proof: &Self::ProofType) -> u32 {
+        proof.get_seal_bytes().len() as u32
+    }
+
+    fn proof_size_breakdown(proof: &Self::ProofType) -> Vec<(&'static str, u32)> {
+        receipt_breakdown(proof)
+    }
+
+    fn new(spec: Self::Spec) -> Self {
+        let (document, path) = random_document(spec as usize);
+
+        // guest input: [path_len, document_len, path, document]
+        let mut guest_input = Vec::from((path.len() as u32).to_le_bytes());
+        guest_input.extend_from_slice(&(document.len() as u32).to_le_bytes());
+        guest_input.extend_from_slice(path.as_bytes());
+        guest_input.extend_from_slice(&document);
+
+        let image = std::fs::read(METHOD_PATH).expect("image");
+        let mut guest = Guest::new(image, METHOD_ID);
+        guest.add_input_u8_slice(guest_input.as_slice());
+
+        Job {
+            spec,
+            guest,
+            document,
+            path,
+        }
+    }
+
+    fn spec(&self) -> &Self::Spec {
+        &self.spec
+    }
+
+    fn job_params(&self) -> String {
+        format!("path={}", self.path)
+    }
+
+    fn host_compute(&mut self) -> Option<Self::ComputeOut> {
+        let value: Value = serde_json::from_slice(&self.document).unwrap();
+        let field = value.pointer(&self.path).and_then(Value::as_str).unwrap();
+
+        Some(field.to_string())
+    }
+
+    fn guest_setup(&mut self) {
+        self.guest.setup();
+    }
+
+    fn guest_compute(&mut self) -> (Self::ComputeOut, Self::ProofType) {
+        let receipt = self.guest.prove();
+
+        let result: String = from_slice(&receipt.journal).unwrap();
+        (result, receipt)
+    }
+
+    fn verify_proof(&self, _output: &Self::ComputeOut, proof: &Self::ProofType) -> bool {
+        let result = proof.verify(&METHOD_ID);
+
+        match result {
+            Ok(_) => true,
+            Err(err) => {
+                println!("{}", err);
+                false
+            }
+        }
+    }
+}
@@ -0,0 +1,118 @@
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use risc0_zkvm::serde::to_vec;
+use risc0_zkvm::sha::{Digest, DIGEST_WORDS};
+use risc0_zkvm::Receipt;
+use rustbench::Benchmark;
+use sha2::{Digest as _, Sha256};
+
+use super::{receipt_breakdown, Guest};
+
+/// Multiplies two random n×n matrices with entries below 256 and commits the
+/// SHA-256 hash of the product. The job size is n³, the number of
+/// multiply-adds, so proof cost can be read against it directly.
+pub struct Job {
+    pub spec: u32,
+    pub guest: Guest,
+    pub a: Vec<u32>,
+    pub b: Vec<u32>,
+}
+
+pub fn new_jobs() -> Vec<<Job as Benchmark>::Spec> {
+    vec![4, 8, 16, 32, 64]
+}
+
+/// Two random n×n matrices in row-major order, the same as in the Miden job.
+fn random_matrices(n: usize) -> (Vec<u32>, Vec<u32>) {
+    let mut rand = StdRng::seed_from_u64(1337);
+    let a = (0..n * n)
+        .map(|_| rand.gen_range(0..256u64) as u32)
+        .collect();
+    let b = (0..n * n)
+        .map(|_| rand.gen_range(0..256u64) as u32)
+        .collect();
+    (a, b)
+}
+
+const METHOD_ID: [u32; DIGEST_WORDS] = risczero_benchmark_methods::MATMUL_ID;
+const METHOD_PATH: &'static str = risczero_benchmark_methods::MATMUL_PATH;
+
+impl Benchmark for Job {
+    const NAME: &'static str = "matmul";
+    type Spec = u32;
+    type ComputeOut = Digest;
+    type ProofType = Receipt;
+
+    fn job_size(spec: &Self::Spec) -> u32 {
+        spec.pow(3)
+    }
+
+    fn output_size_bytes(_output: &Self::ComputeOut, proof: &Self::ProofType) -> u32 {
+        proof.get_journal_bytes().len() as u32
+    }
+
+    fn proof_size_bytes(proof: &Self::ProofType) -> u32 {
+        proof.get_seal_bytes().len() as u32
+    }
+
+    fn proof_size_breakdown(proof: &Self::ProofType) -> Vec<(&'static str, u32)> {
+        receipt_breakdown(proof)
+    }
+
+    fn new(spec: Self::Spec) -> Self {
+        let (a, b) = random_matrices(spec as usize);
+
+        // guest input: [n, A, B]
+        let image = std::fs::read(METHOD_PATH).expect("image");
+        let mut guest = Guest::new(image, METHOD_ID);
+        guest.add_input_u32_slice(&to_vec(&spec).unwrap());
+        guest.add_input_u32_slice(&a);
+        guest.add_input_u32_slice(&b);
+
+        Job { spec, guest, a, b }
+    }
+
+    fn spec(&self) -> &Self::Spec {
+        &self.spec
+    }
+
+    fn job_params(&self) -> String {
+        format!("n={}", self.spec)
+    }
+
+    fn host_compute(&mut self) -> Option<Self::ComputeOut> {
+        let n = self.spec as usize;
+
+        let mut hasher = Sha256::new();
+        for i in 0..n {
+            for j in 0..n {
+                let sum: u32 = (0..n).map(|k| self.a[i * n + k] * self.b[k * n + j]).sum();
+                hasher.update(sum.to_le_bytes());
+            }
+        }
+
+        Some(Digest::try_from(&hasher.finalize()[..]).unwrap())
+    }
+
+    fn guest_setup(&mut self) {
+        self.guest.setup();
+    }
+
+    fn guest_compute(&mut self) -> (Self::ComputeOut, Self::ProofType) {
+        let receipt = self.guest.prove();
+
+        let result = Digest::try_from(receipt.get_journal_bytes()).unwrap();
+        (result, receipt)
+    }
+
+    fn verify_proof(&self, _output: &Self::ComputeOut, proof: &Self::ProofType) -> bool {
+        let result = proof.verify(&METHOD_ID);
+
+        match result {
+            Ok(_) => true,
+            Err(err) => {
+                println!("{}", err);
+                false
+            }
+        }
+    }
+}
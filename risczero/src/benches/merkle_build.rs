@@ -0,0 +1,130 @@
+use rand::{rngs::StdRng, RngCore, SeedableRng};
+use risc0_zkvm::sha::{Digest, DIGEST_WORDS};
+use risc0_zkvm::Receipt;
+use rustbench::Benchmark;
+use sha2::{Digest as _, Sha256};
+
+use super::{receipt_breakdown, Guest};
+
+/// Computes the root of a dense binary Merkle tree over `spec` random 32-byte
+/// leaves in the guest and commits it. The job size is the number of hashed
+/// nodes, n - 1.
+pub struct Job {
+    pub spec: u32,
+    pub guest: Guest,
+    pub leaves: Vec<[u8; 32]>,
+}
+
+pub fn new_jobs() -> Vec<<Job as Benchmark>::Spec> {
+    vec![1 << 8, 1 << 10, 1 << 12, 1 << 14]
+}
+
+fn random_leaves(n: usize) -> Vec<[u8; 32]> {
+    let mut rand = StdRng::seed_from_u64(1337);
+    (0..n)
+        .map(|_| {
+            let mut leaf = [0u8; 32];
+            rand.fill_bytes(&mut leaf);
+            leaf
+        })
+        .collect()
+}
+
+const METHOD_ID: [u32; DIGEST_WORDS] = risczero_benchmark_methods::MERKLE_BUILD_ID;
+const METHOD_PATH: &'static str = risczero_benchmark_methods::MERKLE_BUILD_PATH;
+
+impl Benchmark for Job {
+    const NAME: &'static str = "merkle_build";
+    type Spec = u32;
+    type ComputeOut = Digest;
+    type ProofType = Receipt;
+
+    fn job_size(spec: &Self::Spec) -> u32 {
+        *spec - 1
+    }
+
+    fn output_size_bytes(_output: &Self::ComputeOut, proof: &Self::ProofType) -> u32 {
+        proof.get_journal_bytes().len() as u32
+    }
+
+    fn proof_size_bytes(proof: &Self::ProofType) -> u32 {
+        proof.get_seal_bytes().len() as u32
+    }
+
+    fn proof_size_breakdown(proof: &Self::ProofType) -> Vec<(&'static str, u32)> {
+        receipt_breakdown(proof)
+    }
+
+    fn new(spec: Self::Spec) -> Self {
+        assert!(
+            spec.is_power_of_two() && spec > 1,
+            "n must be a power of two"
+        );
+        let leaves = random_leaves(spec as usize);
+
+        // guest input: [n, leaves]
+        let mut guest_input = Vec::from(spec.to_le_bytes());
+        for leaf in leaves.iter() {
+            guest_input.extend_from_slice(leaf);
+        }
+
+        let image = std::fs::read(METHOD_PATH).expect("image");
+        let mut guest = Guest::new(image, METHOD_ID);
+        guest.add_input_u8_slice(guest_input.as_slice());
+
+        Job {
+            spec,
+            guest,
+            leaves,
+        }
+    }
+
+    fn spec(&self) -> &Self::Spec {
+        &self.spec
+    }
+
+    fn job_params(&self) -> String {
+        format!("leaves={}", self.spec)
+    }
+
+    fn host_compute(&mut self) -> Option<Self::ComputeOut> {
+        let mut nodes = self.leaves.clone();
+        while nodes.len() > 1 {
+            nodes = nodes
+                .chunks_exact(2)
+                .map(|pair| {
+                    Sha256::new()
+                        .chain_update(pair[0])
+                        .chain_update(pair[1])
+                        .finalize()
+                        .into()
+                })
+                .collect();
+        }
+
+        Some(Digest::try_from(nodes[0].as_slice()).unwrap())
+    }
+
+    fn guest_setup(&mut self) {
+        self.guest.setup();
+    }
+
+    fn guest_compute(&mut self) -> (Self::ComputeOut, Self::ProofType) {
+        let receipt = self.guest.prove();
+
+        let result = Digest::try_from(receipt.get_journal_bytes()).unwrap();
+        (result, receipt)
+    }
+
+    fn verify_proof(&self, _output: &Self::ComputeOut, proof: &Self::ProofType) -> bool {
+        let result = proof.verify(&METHOD_ID);
+
+        match result {
+            Ok(_) => true,
+            Err(err) => {
+                println!("{}", err);
+                false
+            }
+        }
+    }
+}
@@ -0,0 +1,151 @@
+use std::collections::HashSet;
+
+use rand::{rngs::StdRng, Rng, RngCore, SeedableRng};
+use risc0_zkvm::serde::to_vec;
+use risc0_zkvm::sha::{Digest, DIGEST_WORDS};
+use risc0_zkvm::Receipt;
+use rustbench::Benchmark;
+
+use super::{receipt_breakdown, Guest};
+use crate::merkle::MerkleTree;
+
+/// Verifies `num_paths` authentication paths of a Merkle tree of the given
+/// depth. So, for num_paths=10 and depth=32 we verify 10 Merkle paths of
+/// depth 32, matching the Miden `merkle_path_rescue_prime` job.
+pub struct Spec {
+    pub num_paths: u32,
+    pub depth: u32,
+}
+
+pub struct Job {
+    pub spec: Spec,
+    pub guest: Guest,
+    pub root: Digest,
+    pub paths: Vec<(u64, Digest, Vec<Digest>)>,
+}
+
+pub fn new_jobs() -> Vec<<Job as Benchmark>::Spec> {
+    let mut jobs = Vec::new();
+    for num_paths in [1, 10, 100] {
+        jobs.push(Spec {
+            num_paths,
+            depth: 32,
+        });
+    }
+    jobs
+}
+
+const METHOD_ID: [u32; DIGEST_WORDS] = risczero_benchmark_methods::MERKLE_PATH_SHA2_ID;
+const METHOD_PATH: &'static str = risczero_benchmark_methods::MERKLE_PATH_SHA2_PATH;
+
+impl Benchmark for Job {
+    const NAME: &'static str = "merkle_path_sha2";
+    type Spec = Spec;
+    type ComputeOut = Digest;
+    type ProofType = Receipt;
+
+    fn job_size(spec: &Self::Spec) -> u32 {
+        spec.num_paths
+    }
+
+    fn output_size_bytes(_output: &Self::ComputeOut, proof: &Self::ProofType) -> u32 {
+        proof.get_journal_bytes().len() as u32
+    }
+
+    fn proof_size_bytes(proof: &Self::ProofType) -> u32 {
+        proof.get_seal_bytes().len() as u32
+    }
+
+    fn proof_size_breakdown(proof: &Self::ProofType) -> Vec<(&'static str, u32)> {
+        receipt_breakdown(proof)
+    }
+
+    fn new(spec: Self::Spec) -> Self {
+        // We fill the tree with one random leaf per path at distinct random indices
+        let mut rand = StdRng::seed_from_u64(1337);
+        let mut tree = MerkleTree::new(spec.depth);
+        let mut leaves = Vec::new();
+        let mut used = HashSet::new();
+        while leaves.len() < spec.num_paths as usize {
+            let index = match spec.depth {
+                64 => rand.next_u64(),
+                depth => rand.gen_range(0..1u64 << depth),
+            };
+            if !used.insert(index) {
+                continue;
+            }
+
+            let mut leaf = [0u8; 32];
+            rand.fill_bytes(&mut leaf);
+            let leaf = Digest::from(leaf);
+
+            tree.insert(index, leaf);
+            leaves.push((index, leaf));
+        }
+
+        let root = tree.root();
+        let paths: Vec<(u64, Digest, Vec<Digest>)> = leaves
+            .into_iter()
+            .map(|(index, leaf)| (index, leaf, tree.path(index)))
+            .collect();
+
+        // guest input: [depth, num_paths, root, (index, leaf, path)...]
+        let mut guest_input = Vec::new();
+        guest_input.extend(to_vec(&spec.depth).unwrap());
+        guest_input.extend(to_vec(&spec.num_paths).unwrap());
+        guest_input.extend(to_vec(&root).unwrap());
+        for (index, leaf, path) in paths.iter() {
+            guest_input.extend(to_vec(index).unwrap());
+            guest_input.extend(to_vec(leaf).unwrap());
+            for sibling in path {
+                guest_input.extend(to_vec(sibling).unwrap());
+            }
+        }
+
+        let image = std::fs::read(METHOD_PATH).expect("image");
+        let mut guest = Guest::new(image, METHOD_ID);
+        guest.add_input_u32_slice(guest_input.as_slice());
+
+        Job {
+            spec,
+            guest,
+            root,
+            paths,
+        }
+    }
+
+    fn spec(&self) -> &Self::Spec {
+        &self.spec
+    }
+
+    fn host_compute(&mut self) -> Option<Self::ComputeOut> {
+        for (index, leaf, path) in self.paths.iter() {
+            assert_eq!(MerkleTree::root_from_path(*index, leaf, path), self.root);
+        }
+
+        Some(self.root)
+    }
+
+    fn guest_setup(&mut self) {
+        self.guest.setup();
+    }
+
+    fn guest_compute(&mut self) -> (Self::ComputeOut, Self::ProofType) {
+        let receipt = self.guest.prove();
+
+        let guest_output = Digest::try_from(receipt.get_journal_bytes()).unwrap();
+        (guest_output, receipt)
+    }
+
+    fn verify_proof(&self, _output: &Self::ComputeOut, proof: &Self::ProofType) -> bool {
+        let result = proof.verify(&METHOD_ID);
+
+        match result {
+            Ok(_) => true,
+            Err(err) => {
+                println!("{}", err);
+                false
+            }
+        }
+    }
+}
@@ -1,2 +1,81 @@
+use risc0_circuit_rv32im::CircuitImpl;
+use risc0_zkp::adapter::CircuitInfo;
+use risc0_zkvm::sha::DIGEST_WORDS;
+use risc0_zkvm::{Prover, Receipt};
+
 pub mod big_sha2;
+pub mod ecdsa_secp256k1;
+pub mod ed25519;
+pub mod eth_headers;
+pub mod fibonacci;
+pub mod image_transform;
+pub mod iter_blake3;
+pub mod iter_keccak;
 pub mod iter_sha2;
+pub mod json_parse;
+pub mod matmul;
+pub mod merkle_build;
+pub mod merkle_path_sha2;
+pub mod random_access;
+pub mod rsa_2048;
+pub mod smt_update;
+pub mod sort;
+pub mod sudoku;
+pub mod u256_mulmod;
+
+/// Guest image of a job together with the input it is run on. A `Prover`
+/// appends to the same journal every time it runs, so each proof is generated
+/// by a fresh prover built from these.
+pub struct Guest {
+    image: Vec<u8>,
+    image_id: [u32; DIGEST_WORDS],
+    input: Vec<u8>,
+    prover: Option<Prover<'static>>,
+}
+
+impl Guest {
+    pub fn new(image: Vec<u8>, image_id: [u32; DIGEST_WORDS]) -> Self {
+        Guest {
+            image,
+            image_id,
+            input: Vec::new(),
+            prover: None,
+        }
+    }
+
+    pub fn add_input_u8_slice(&mut self, slice: &[u8]) {
+        self.input.extend_from_slice(slice);
+    }
+
+    pub fn add_input_u32_slice(&mut self, slice: &[u32]) {
+        self.input
+            .extend(slice.iter().flat_map(|word| word.to_ne_bytes()));
+    }
+
+    /// Builds the prover for the next proof. Creating a `Prover` parses the
+    /// image, so this runs in `guest_setup`, where it is not timed.
+    pub fn setup(&mut self) {
+        let mut prover = Prover::new(&self.image, self.image_id).expect("prover");
+        prover.add_input_u8_slice(&self.input);
+        self.prover = Some(prover);
+    }
+
+    pub fn prove(&mut self) -> Receipt {
+        let mut prover = self.prover.take().expect("setup before every proof");
+        prover.run().expect("receipt")
+    }
+}
+
+/// Splits a receipt into the seal, the journal and the metadata at the head of
+/// the seal (the circuit outputs, including the image ID and the journal digest,
+/// followed by the cycle count).
+pub fn receipt_breakdown(receipt: &Receipt) -> Vec<(&'static str, u32)> {
+    let metadata = (CircuitImpl::OUTPUT_SIZE + 1) * 4;
+    let seal = receipt.get_seal_bytes().len() - metadata;
+
+    vec![
+        ("metadata", metadata as u32),
+        ("seal", seal as u32),
+        ("journal", receipt.get_journal_bytes().len() as u32),
+    ]
+}
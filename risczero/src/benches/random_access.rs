@@ -0,0 +1,121 @@
+use risc0_zkvm::serde::{from_slice, to_vec};
+use risc0_zkvm::sha::DIGEST_WORDS;
+use risc0_zkvm::Receipt;
+use rustbench::Benchmark;
+
+use super::{receipt_breakdown, Guest};
+
+/// Performs `num_ops` read-modify-writes at pseudo-random addresses within a
+/// working set of `working_set` words and commits the sum of the values read.
+/// The addresses come from the same 32-bit LCG as in the Miden job.
+pub struct Spec {
+    pub num_ops: u32,
+    /// A power of two, up to 2^20 words.
+    pub working_set: u32,
+}
+
+pub struct Job {
+    pub spec: Spec,
+    pub guest: Guest,
+}
+
+pub fn new_jobs() -> Vec<<Job as Benchmark>::Spec> {
+    let mut jobs = Vec::new();
+    for working_set in [1 << 8, 1 << 12, 1 << 16, 1 << 20] {
+        for num_ops in [1000, 10_000] {
+            jobs.push(Spec {
+                num_ops,
+                working_set,
+            });
+        }
+    }
+    jobs
+}
+
+const SEED: u32 = 1337;
+const LCG_MUL: u32 = 1664525;
+const LCG_ADD: u32 = 1013904223;
+
+const METHOD_ID: [u32; DIGEST_WORDS] = risczero_benchmark_methods::RANDOM_ACCESS_ID;
+const METHOD_PATH: &'static str = risczero_benchmark_methods::RANDOM_ACCESS_PATH;
+
+impl Benchmark for Job {
+    const NAME: &'static str = "random_access";
+    type Spec = Spec;
+    type ComputeOut = u32;
+    type ProofType = Receipt;
+
+    fn job_size(spec: &Self::Spec) -> u32 {
+        spec.num_ops
+    }
+
+    fn output_size_bytes(_output: &Self::ComputeOut, proof: &Self::ProofType) -> u32 {
+        proof.get_journal_bytes().len() as u32
+    }
+
+    fn proof_size_bytes(proof: &Self::ProofType) -> u32 {
+        proof.get_seal_bytes().len() as u32
+    }
+
+    fn proof_size_breakdown(proof: &Self::ProofType) -> Vec<(&'static str, u32)> {
+        receipt_breakdown(proof)
+    }
+
+    fn new(spec: Self::Spec) -> Self {
+        // guest input: [num_ops, working_set, seed]
+        let image = std::fs::read(METHOD_PATH).expect("image");
+        let mut guest = Guest::new(image, METHOD_ID);
+        guest.add_input_u32_slice(&to_vec(&spec.num_ops).unwrap());
+        guest.add_input_u32_slice(&to_vec(&spec.working_set).unwrap());
+        guest.add_input_u32_slice(&to_vec(&SEED).unwrap());
+
+        Job { spec, guest }
+    }
+
+    fn spec(&self) -> &Self::Spec {
+        &self.spec
+    }
+
+    fn job_params(&self) -> String {
+        format!("working_set={}", self.spec.working_set)
+    }
+
+    fn host_compute(&mut self) -> Option<Self::ComputeOut> {
+        let shift = 32 - self.spec.working_set.trailing_zeros();
+
+        let mut memory = vec![0u32; self.spec.working_set as usize];
+        let mut x = SEED;
+        let mut sum: u32 = 0;
+        for _ in 0..self.spec.num_ops {
+            x = x.wrapping_mul(LCG_MUL).wrapping_add(LCG_ADD);
+            let addr = (x >> shift) as usize;
+            sum = sum.wrapping_add(memory[addr]);
+            memory[addr] = memory[addr].wrapping_add(x);
+        }
+
+        Some(sum)
+    }
+
+    fn guest_setup(&mut self) {
+        self.guest.setup();
+    }
+
+    fn guest_compute(&mut self) -> (Self::ComputeOut, Self::ProofType) {
+        let receipt = self.guest.prove();
+
+        let result: u32 = from_slice(&receipt.journal).unwrap();
+        (result, receipt)
+    }
+
+    fn verify_proof(&self, _output: &Self::ComputeOut, proof: &Self::ProofType) -> bool {
+        let result = proof.verify(&METHOD_ID);
+
+        match result {
+            Ok(_) => true,
+            Err(err) => {
+                println!("{}", err);
+                false
+            }
+        }
+    }
+}
@@ -0,0 +1,147 @@
+use rand::{rngs::StdRng, RngCore, SeedableRng};
+use risc0_zkvm::serde::from_slice;
+use risc0_zkvm::sha::DIGEST_WORDS;
+use risc0_zkvm::Receipt;
+use rsa::{traits::PublicKeyParts, BigUint, Pkcs1v15Sign, RsaPrivateKey, RsaPublicKey};
+use rustbench::Benchmark;
+use sha2::{Digest, Sha256};
+
+use super::{receipt_breakdown, Guest};
+
+pub struct Spec {
+    pub signatures: Vec<(RsaPublicKey, [u8; 64], Vec<u8>)>,
+}
+
+/// Verifies a number of RSA-2048 PKCS#1 v1.5 signatures with SHA-256 over
+/// random 64-byte messages and commits the count of valid signatures.
+pub struct Job {
+    pub spec: Spec,
+    pub guest: Guest,
+}
+
+pub fn new_jobs() -> Vec<<Job as Benchmark>::Spec> {
+    // Key generation takes seconds per key, so the signatures are made here
+    // rather than in the timed job setup. Smaller jobs reuse the first keys.
+    let signatures = random_signatures(2);
+    [1, 2]
+        .into_iter()
+        .map(|n| Spec {
+            signatures: signatures[..n].to_vec(),
+        })
+        .collect()
+}
+
+/// Signs `n` random messages, each with a fresh key, like certificates issued
+/// by different authorities. All keys use the public exponent 65537, which
+/// RsaPrivateKey::new picks.
+fn random_signatures(n: usize) -> Vec<(RsaPublicKey, [u8; 64], Vec<u8>)> {
+    let mut rand = StdRng::seed_from_u64(1337);
+    let mut signatures = Vec::new();
+    for _ in 0..n {
+        let private_key = RsaPrivateKey::new(&mut rand, KEY_BITS).expect("key");
+        let mut message = [0u8; 64];
+        rand.fill_bytes(&mut message);
+        let signature = private_key
+            .sign(Pkcs1v15Sign::new::<Sha256>(), &Sha256::digest(message))
+            .expect("signature");
+        signatures.push((private_key.to_public_key(), message, signature));
+    }
+    signatures
+}
+
+const KEY_BITS: usize = 2048;
+const PUBLIC_EXPONENT: u32 = 65537;
+
+const METHOD_ID: [u32; DIGEST_WORDS] = risczero_benchmark_methods::RSA_2048_ID;
+const METHOD_PATH: &'static str = risczero_benchmark_methods::RSA_2048_PATH;
+
+impl Benchmark for Job {
+    const NAME: &'static str = "rsa_2048";
+    type Spec = Spec;
+    type ComputeOut = u32;
+    type ProofType = Receipt;
+
+    fn job_size(spec: &Self::Spec) -> u32 {
+        spec.signatures.len() as u32
+    }
+
+    fn output_size_bytes(_output: &Self::ComputeOut, proof: &Self::ProofType) -> u32 {
+        proof.get_journal_bytes().len() as u32
+    }
+
+    fn proof_size_bytes(proof: &Self::ProofType) -> u32 {
+        proof.get_seal_bytes().len() as u32
+    }
+
+    fn proof_size_breakdown(proof: &Self::ProofType) -> Vec<(&'static str, u32)> {
+        receipt_breakdown(proof)
+    }
+
+    fn new(spec: Self::Spec) -> Self {
+        // guest input: [num_signatures, exponent, (modulus, message, signature)...]
+        let mut guest_input = Vec::from(Self::job_size(&spec).to_le_bytes());
+        guest_input.extend_from_slice(&PUBLIC_EXPONENT.to_le_bytes());
+        for (public_key, message, signature) in spec.signatures.iter() {
+            assert_eq!(public_key.e(), &BigUint::from(PUBLIC_EXPONENT));
+            guest_input.extend_from_slice(&public_key.n().to_bytes_be());
+            guest_input.extend_from_slice(message);
+            guest_input.extend_from_slice(signature);
+        }
+
+        let image = std::fs::read(METHOD_PATH).expect("image");
+        let mut guest = Guest::new(image, METHOD_ID);
+        guest.add_input_u8_slice(guest_input.as_slice());
+
+        Job { spec, guest }
+    }
+
+    fn spec(&self) -> &Self::Spec {
+        &self.spec
+    }
+
+    fn job_params(&self) -> String {
+        format!("key_bits={}", KEY_BITS)
+    }
+
+    fn host_compute(&mut self) -> Option<Self::ComputeOut> {
+        let num_valid = self
+            .spec
+            .signatures
+            .iter()
+            .filter(|(public_key, message, signature)| {
+                public_key
+                    .verify(
+                        Pkcs1v15Sign::new::<Sha256>(),
+                        &Sha256::digest(message),
+                        signature,
+                    )
+                    .is_ok()
+            })
+            .count();
+
+        Some(num_valid as u32)
+    }
+
+    fn guest_setup(&mut self) {
+        self.guest.setup();
+    }
+
+    fn guest_compute(&mut self) -> (Self::ComputeOut, Self::ProofType) {
+        let receipt = self.guest.prove();
+
+        let num_valid: u32 = from_slice(&receipt.journal).unwrap();
+        (num_valid, receipt)
+    }
+
+    fn verify_proof(&self, _output: &Self::ComputeOut, proof: &Self::ProofType) -> bool {
+        let result = proof.verify(&METHOD_ID);
+
+        match result {
+            Ok(_) => true,
+            Err(err) => {
+                println!("{}", err);
+                false
+            }
+        }
+    }
+}
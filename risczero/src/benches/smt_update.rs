@@ -0,0 +1,161 @@
+use std::collections::HashSet;
+
+use rand::{rngs::StdRng, Rng, RngCore, SeedableRng};
+use risc0_zkvm::serde::to_vec;
+use risc0_zkvm::sha::{Digest, DIGEST_WORDS};
+use risc0_zkvm::Receipt;
+use rustbench::Benchmark;
+
+use super::{receipt_breakdown, Guest};
+use crate::merkle::MerkleTree;
+
+/// Applies `num_updates` key/value updates to a sparse Merkle tree of depth 32
+/// holding 1000 leaves and commits the new root, matching the Miden
+/// `smt_update` job. Half of the updates overwrite existing leaves, the other
+/// half insert leaves at new keys. For every update the guest gets the old
+/// leaf and its path, checks them against the current root and recomputes the
+/// root with the new leaf.
+pub struct Job {
+    pub spec: u32,
+    pub guest: Guest,
+    pub leaves: Vec<(u64, Digest)>,
+    pub updates: Vec<(u64, Digest)>,
+}
+
+pub fn new_jobs() -> Vec<<Job as Benchmark>::Spec> {
+    vec![10, 100, 1000]
+}
+
+const DEPTH: u32 = 32;
+const NUM_LEAVES: usize = 1000;
+
+const METHOD_ID: [u32; DIGEST_WORDS] = risczero_benchmark_methods::SMT_UPDATE_ID;
+const METHOD_PATH: &'static str = risczero_benchmark_methods::SMT_UPDATE_PATH;
+
+fn random_leaf(rand: &mut StdRng) -> Digest {
+    let mut leaf = [0u8; 32];
+    rand.fill_bytes(&mut leaf);
+    Digest::from(leaf)
+}
+
+impl Benchmark for Job {
+    const NAME: &'static str = "smt_update";
+    type Spec = u32;
+    type ComputeOut = Digest;
+    type ProofType = Receipt;
+
+    fn job_size(spec: &Self::Spec) -> u32 {
+        *spec
+    }
+
+    fn output_size_bytes(_output: &Self::ComputeOut, proof: &Self::ProofType) -> u32 {
+        proof.get_journal_bytes().len() as u32
+    }
+
+    fn proof_size_bytes(proof: &Self::ProofType) -> u32 {
+        proof.get_seal_bytes().len() as u32
+    }
+
+    fn proof_size_breakdown(proof: &Self::ProofType) -> Vec<(&'static str, u32)> {
+        receipt_breakdown(proof)
+    }
+
+    fn new(spec: Self::Spec) -> Self {
+        // The tree starts with leaves at random keys. Every update either
+        // overwrites one of them or inserts a leaf at a key not used so far.
+        let mut rand = StdRng::seed_from_u64(1337);
+        let mut used = HashSet::new();
+        let mut random_key = |rand: &mut StdRng| loop {
+            let key = rand.gen_range(0..1u64 << DEPTH);
+            if used.insert(key) {
+                break key;
+            }
+        };
+
+        let leaves: Vec<(u64, Digest)> = (0..NUM_LEAVES)
+            .map(|_| (random_key(&mut rand), random_leaf(&mut rand)))
+            .collect();
+        let updates: Vec<(u64, Digest)> = (0..spec)
+            .map(|_| {
+                let key = if rand.gen::<bool>() {
+                    leaves[rand.gen_range(0..leaves.len())].0
+                } else {
+                    random_key(&mut rand)
+                };
+                (key, random_leaf(&mut rand))
+            })
+            .collect();
+
+        let mut tree = MerkleTree::new(DEPTH);
+        for (key, leaf) in leaves.iter() {
+            tree.insert(*key, *leaf);
+        }
+
+        // guest input: [depth, num_updates, root, (index, old_leaf, new_leaf, path)...]
+        // Each path is taken right before its update is applied.
+        let mut guest_input = Vec::new();
+        guest_input.extend(to_vec(&DEPTH).unwrap());
+        guest_input.extend(to_vec(&spec).unwrap());
+        guest_input.extend(to_vec(&tree.root()).unwrap());
+        for (key, leaf) in updates.iter() {
+            guest_input.extend(to_vec(key).unwrap());
+            guest_input.extend(to_vec(&tree.node(0, *key)).unwrap());
+            guest_input.extend(to_vec(leaf).unwrap());
+            for sibling in tree.path(*key) {
+                guest_input.extend(to_vec(&sibling).unwrap());
+            }
+            tree.insert(*key, *leaf);
+        }
+
+        let image = std::fs::read(METHOD_PATH).expect("image");
+        let mut guest = Guest::new(image, METHOD_ID);
+        guest.add_input_u32_slice(guest_input.as_slice());
+
+        Job {
+            spec,
+            guest,
+            leaves,
+            updates,
+        }
+    }
+
+    fn spec(&self) -> &Self::Spec {
+        &self.spec
+    }
+
+    fn job_params(&self) -> String {
+        format!("depth={} leaves={}", DEPTH, NUM_LEAVES)
+    }
+
+    fn host_compute(&mut self) -> Option<Self::ComputeOut> {
+        let mut tree = MerkleTree::new(DEPTH);
+        for (key, leaf) in self.leaves.iter().chain(self.updates.iter()) {
+            tree.insert(*key, *leaf);
+        }
+
+        Some(tree.root())
+    }
+
+    fn guest_setup(&mut self) {
+        self.guest.setup();
+    }
+
+    fn guest_compute(&mut self) -> (Self::ComputeOut, Self::ProofType) {
+        let receipt = self.guest.prove();
+
+        let guest_output = Digest::try_from(receipt.get_journal_bytes()).unwrap();
+        (guest_output, receipt)
+    }
+
+    fn verify_proof(&self, _output: &Self::ComputeOut, proof: &Self::ProofType) -> bool {
+        let result = proof.verify(&METHOD_ID);
+
+        match result {
+            Ok(_) => true,
+            Err(err) => {
+                println!("{}", err);
+                false
+            }
+        }
+    }
+}
@@ -0,0 +1,107 @@
+use rand::{rngs::StdRng, RngCore, SeedableRng};
+use risc0_zkvm::serde::to_vec;
+use risc0_zkvm::sha::{Digest, DIGEST_WORDS};
+use risc0_zkvm::Receipt;
+use rustbench::Benchmark;
+use sha2::{Digest as _, Sha256};
+
+use super::{receipt_breakdown, Guest};
+
+/// Sorts a random array of u32 values in the guest and commits the SHA-256
+/// hash of the sorted array.
+pub struct Job {
+    pub spec: u32,
+    pub guest: Guest,
+    pub values: Vec<u32>,
+}
+
+pub fn new_jobs() -> Vec<<Job as Benchmark>::Spec> {
+    vec![1 << 10, 1 << 12, 1 << 14, 1 << 16]
+}
+
+/// The random values to sort, the same as in the Miden job.
+fn random_values(n: usize) -> Vec<u32> {
+    let mut rand = StdRng::seed_from_u64(1337);
+    (0..n).map(|_| rand.next_u32()).collect()
+}
+
+const METHOD_ID: [u32; DIGEST_WORDS] = risczero_benchmark_methods::SORT_ID;
+const METHOD_PATH: &'static str = risczero_benchmark_methods::SORT_PATH;
+
+impl Benchmark for Job {
+    const NAME: &'static str = "sort";
+    type Spec = u32;
+    type ComputeOut = Digest;
+    type ProofType = Receipt;
+
+    fn job_size(spec: &Self::Spec) -> u32 {
+        *spec
+    }
+
+    fn output_size_bytes(_output: &Self::ComputeOut, proof: &Self::ProofType) -> u32 {
+        proof.get_journal_bytes().len() as u32
+    }
+
+    fn proof_size_bytes(proof: &Self::ProofType) -> u32 {
+        proof.get_seal_bytes().len() as u32
+    }
+
+    fn proof_size_breakdown(proof: &Self::ProofType) -> Vec<(&'static str, u32)> {
+        receipt_breakdown(proof)
+    }
+
+    fn new(spec: Self::Spec) -> Self {
+        let values = random_values(spec as usize);
+
+        // guest input: [n, values]
+        let image = std::fs::read(METHOD_PATH).expect("image");
+        let mut guest = Guest::new(image, METHOD_ID);
+        guest.add_input_u32_slice(&to_vec(&spec).unwrap());
+        guest.add_input_u32_slice(&values);
+
+        Job {
+            spec,
+            guest,
+            values,
+        }
+    }
+
+    fn spec(&self) -> &Self::Spec {
+        &self.spec
+    }
+
+    fn host_compute(&mut self) -> Option<Self::ComputeOut> {
+        let mut sorted = self.values.clone();
+        sorted.sort_unstable();
+
+        let mut hasher = Sha256::new();
+        for value in sorted {
+            hasher.update(value.to_le_bytes());
+        }
+
+        Some(Digest::try_from(&hasher.finalize()[..]).unwrap())
+    }
+
+    fn guest_setup(&mut self) {
+        self.guest.setup();
+    }
+
+    fn guest_compute(&mut self) -> (Self::ComputeOut, Self::ProofType) {
+        let receipt = self.guest.prove();
+
+        let result = Digest::try_from(receipt.get_journal_bytes()).unwrap();
+        (result, receipt)
+    }
+
+    fn verify_proof(&self, _output: &Self::ComputeOut, proof: &Self::ProofType) -> bool {
+        let result = proof.verify(&METHOD_ID);
+
+        match result {
+            Ok(_) => true,
+            Err(err) => {
+                println!("{}", err);
+                false
+            }
+        }
+    }
+}
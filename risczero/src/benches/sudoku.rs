@@ -0,0 +1,145 @@
+use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
+use risc0_zkvm::sha::{Digest, DIGEST_WORDS};
+use risc0_zkvm::Receipt;
+use rustbench::Benchmark;
+use sha2::{Digest as _, Sha256};
+
+use super::{receipt_breakdown, Guest};
+
+/// Checks the solutions of `spec` Sudoku puzzles in the guest and commits the
+/// SHA-256 hash of the puzzles. The solutions are private inputs, so the
+/// receipt reveals nothing about them beyond their existence.
+pub struct Job {
+    pub spec: u32,
+    pub guest: Guest,
+    pub puzzles: Vec<([u8; 81], [u8; 81])>,
+}
+
+pub fn new_jobs() -> Vec<<Job as Benchmark>::Spec> {
+    vec![1, 10, 100]
+}
+
+/// Rows or columns in a random order that keeps them within their band.
+fn shuffled_lines(rand: &mut StdRng) -> Vec<usize> {
+    let mut bands = [0, 1, 2];
+    bands.shuffle(rand);
+    bands
+        .iter()
+        .flat_map(|band| {
+            let mut lines = [0, 1, 2];
+            lines.shuffle(rand);
+            lines.map(|line| 3 * band + line)
+        })
+        .collect()
+}
+
+/// Random puzzles with their solutions, the same as in the Miden job. Every
+/// solution is a shuffled copy of a fixed grid, and about half of its cells
+/// are cleared to get the puzzle. Empty cells are 0.
+fn random_puzzles(n: usize) -> Vec<([u8; 81], [u8; 81])> {
+    let mut rand = StdRng::seed_from_u64(1337);
+    (0..n)
+        .map(|_| {
+            let mut digits: Vec<u8> = (1..=9).collect();
+            digits.shuffle(&mut rand);
+            let rows = shuffled_lines(&mut rand);
+            let cols = shuffled_lines(&mut rand);
+
+            let mut solution = [0u8; 81];
+            for (i, cell) in solution.iter_mut().enumerate() {
+                let (r, c) = (rows[i / 9], cols[i % 9]);
+                *cell = digits[(3 * (r % 3) + r / 3 + c) % 9];
+            }
+            let mut puzzle = solution;
+            for cell in puzzle.iter_mut() {
+                if rand.gen::<bool>() {
+                    *cell = 0;
+                }
+            }
+            (puzzle, solution)
+        })
+        .collect()
+}
+
+const METHOD_ID: [u32; DIGEST_WORDS] = risczero_benchmark_methods::SUDOKU_ID;
+const METHOD_PATH: &'static str = risczero_benchmark_methods::SUDOKU_PATH;
+
+impl Benchmark for Job {
+    const NAME: &'static str = "sudoku";
+    type Spec = u32;
+    type ComputeOut = Digest;
+    type ProofType = Receipt;
+
+    fn job_size(spec: &Self::Spec) -> u32 {
+        *spec
+    }
+
+    fn output_size_bytes(_output: &Self::ComputeOut, proof: &Self::ProofType) -> u32 {
+        proof.get_journal_bytes().len() as u32
+    }
+
+    fn proof_size_bytes(proof: &Self::ProofType) -> u32 {
+        proof.get_seal_bytes().len() as u32
+    }
+
+    fn proof_size_breakdown(proof: &Self::ProofType) -> Vec<(&'static str, u32)> {
+        receipt_breakdown(proof)
+    }
+
+    fn new(spec: Self::Spec) -> Self {
+        let puzzles = random_puzzles(spec as usize);
+
+        // guest input: [num_puzzles, (puzzle, solution)...]
+        let mut guest_input = Vec::from(spec.to_le_bytes());
+        for (puzzle, solution) in puzzles.iter() {
+            guest_input.extend_from_slice(puzzle);
+            guest_input.extend_from_slice(solution);
+        }
+
+        let image = std::fs::read(METHOD_PATH).expect("image");
+        let mut guest = Guest::new(image, METHOD_ID);
+        guest.add_input_u8_slice(guest_input.as_slice());
+
+        Job {
+            spec,
+            guest,
+            puzzles,
+        }
+    }
+
+    fn spec(&self) -> &Self::Spec {
+        &self.spec
+    }
+
+    fn host_compute(&mut self) -> Option<Self::ComputeOut> {
+        let mut hasher = Sha256::new();
+        for (puzzle, _) in self.puzzles.iter() {
+            hasher.update(puzzle);
+        }
+
+        Some(Digest::try_from(&hasher.finalize()[..]).unwrap())
+    }
+
+    fn guest_setup(&mut self) {
+        self.guest.setup();
+    }
+
+    fn guest_compute(&mut self) -> (Self::ComputeOut, Self::ProofType) {
+        let receipt = self.guest.prove();
+
+        let result = Digest::try_from(receipt.get_journal_bytes()).unwrap();
+        (result, receipt)
+    }
+
+    fn verify_proof(&self, _output: &Self::ComputeOut, proof: &Self::ProofType) -> bool {
+        let result = proof.verify(&METHOD_ID);
+
+        match result {
+            Ok(_) => true,
+            Err(err) => {
+                println!("{}", err);
+                false
+            }
+        }
+    }
+}
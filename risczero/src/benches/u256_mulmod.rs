@@ -0,0 +1,121 @@
+use num_bigint::BigUint;
+use rand::{rngs::StdRng, RngCore, SeedableRng};
+use risc0_zkvm::serde::from_slice;
+use risc0_zkvm::sha::DIGEST_WORDS;
+use risc0_zkvm::Receipt;
+use rustbench::Benchmark;
+
+use super::{receipt_breakdown, Guest};
+
+/// Computes a * b^n modulo the secp256k1 base field prime with n chained
+/// 256-bit modular multiplications, using crypto-bigint's Montgomery
+/// arithmetic on 32-bit limbs in the guest.
+pub struct Job {
+    pub spec: u32,
+    pub guest: Guest,
+    pub a: BigUint,
+    pub b: BigUint,
+}
+
+pub fn new_jobs() -> Vec<<Job as Benchmark>::Spec> {
+    vec![1, 10, 100, 1000]
+}
+
+/// The secp256k1 base field prime, 2^256 - 2^32 - 977
+fn modulus() -> BigUint {
+    (BigUint::from(1u32) << 256) - (BigUint::from(1u32) << 32) - BigUint::from(977u32)
+}
+
+/// Two random field elements, the same as in the Miden job.
+fn random_operands() -> (BigUint, BigUint) {
+    let mut rand = StdRng::seed_from_u64(1337);
+    let mut random_element = || {
+        let mut bytes = [0u8; 32];
+        rand.fill_bytes(&mut bytes);
+        BigUint::from_bytes_le(&bytes) % modulus()
+    };
+    let a = random_element();
+    let b = random_element();
+    (a, b)
+}
+
+/// The 32-byte little-endian encoding of a 256-bit number.
+fn to_bytes(x: &BigUint) -> Vec<u8> {
+    let mut bytes = x.to_bytes_le();
+    bytes.resize(32, 0);
+    bytes
+}
+
+const METHOD_ID: [u32; DIGEST_WORDS] = risczero_benchmark_methods::U256_MULMOD_ID;
+const METHOD_PATH: &'static str = risczero_benchmark_methods::U256_MULMOD_PATH;
+
+impl Benchmark for Job {
+    const NAME: &'static str = "u256_mulmod";
+    type Spec = u32;
+    type ComputeOut = Vec<u32>;
+    type ProofType = Receipt;
+
+    fn job_size(spec: &Self::Spec) -> u32 {
+        *spec
+    }
+
+    fn output_size_bytes(_output: &Self::ComputeOut, proof: &Self::ProofType) -> u32 {
+        proof.get_journal_bytes().len() as u32
+    }
+
+    fn proof_size_bytes(proof: &Self::ProofType) -> u32 {
+        proof.get_seal_bytes().len() as u32
+    }
+
+    fn proof_size_breakdown(proof: &Self::ProofType) -> Vec<(&'static str, u32)> {
+        receipt_breakdown(proof)
+    }
+
+    fn new(spec: Self::Spec) -> Self {
+        let (a, b) = random_operands();
+
+        // guest input: [n, modulus, a, b]
+        let mut guest_input = Vec::from(spec.to_le_bytes());
+        guest_input.extend(to_bytes(&modulus()));
+        guest_input.extend(to_bytes(&a));
+        guest_input.extend(to_bytes(&b));
+
+        let image = std::fs::read(METHOD_PATH).expect("image");
+        let mut guest = Guest::new(image, METHOD_ID);
+        guest.add_input_u8_slice(guest_input.as_slice());
+
+        Job { spec, guest, a, b }
+    }
+
+    fn spec(&self) -> &Self::Spec {
+        &self.spec
+    }
+
+    fn host_compute(&mut self) -> Option<Self::ComputeOut> {
+        let p = modulus();
+        let result = &self.a * self.b.modpow(&BigUint::from(self.spec), &p) % &p;
+
+        let mut limbs: Vec<u32> = result.iter_u32_digits().collect();
+        limbs.resize(8, 0);
+        Some(limbs)
+    }
+
+    fn guest_compute(&mut self) -> (Self::ComputeOut, Self::ProofType) {
+        let receipt = self.guest.prove();
+
+        let limbs: [u32; 8] = from_slice(&receipt.journal).unwrap();
+        (limbs.to_vec(), receipt)
+    }
+
+    fn verify_proof(&self, _output: &Self::ComputeOut, proof: &Self::ProofType) -> bool {
+        let result = proof.verify(&METHOD_ID);
+
+        match result {
+            Ok(_) => true,
+            Err(err) => {
+                println!("{}", err);
+                false
+            }
+        }
+    }
+}
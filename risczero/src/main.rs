@@ -1,10 +1,14 @@
 use clap::{Parser, Subcommand};
 use std::path::PathBuf;
+use std::time::Duration;
 
 mod benches;
+mod merkle;
+mod prover_info;
 
 use benches::*;
-use rustbench::{init_logging, run_jobs};
+use prover_info::RiscZeroInfo;
+use rustbench::{init_logging, run_jobs, write_prover_info, RunConfig};
 
 #[derive(Parser)]
 #[command(author, version, about, long_about = None)]
@@ -13,6 +17,14 @@ struct Cli {
     #[arg(long, value_name = "FILE")]
     out: PathBuf,
 
+    // Number of times each proof is generated
+    #[arg(long, value_name = "N", default_value_t = 1)]
+    proof_runs: u32,
+
+    // Minimum time spent repeating proof verification, in milliseconds
+    #[arg(long, value_name = "MS", default_value_t = 1000)]
+    min_verify_ms: u64,
+
     #[command(subcommand)]
     command: Command,
 }
@@ -21,20 +33,112 @@ struct Cli {
 enum Command {
     All,
     BigSha2,
+    EcdsaSecp256k1,
+    Ed25519,
+    Fibonacci,
+    IterBlake3,
+    IterKeccak,
     IterSha2,
+    Matmul,
+    MerklePathSha2,
+    Sort,
+    RandomAccess,
+    JsonParse,
+    SmtUpdate,
+    EthHeaders,
+    ImageTransform,
+    U256Mulmod,
+    Rsa2048,
+    Sudoku,
+    MerkleBuild,
 }
 
 fn main() {
     init_logging();
     let cli = Cli::parse();
 
+    let config = RunConfig {
+        proof_runs: cli.proof_runs,
+        min_verify_duration: Duration::from_millis(cli.min_verify_ms),
+    };
+
     let prover = String::from("risczero");
 
+    write_prover_info(&prover, &cli.out, &RiscZeroInfo);
+
     if cli.command == Command::All || cli.command == Command::BigSha2 {
-        run_jobs::<big_sha2::Job>(&prover, &cli.out, big_sha2::new_jobs());
+        run_jobs::<big_sha2::Job>(&prover, &cli.out, &config, big_sha2::new_jobs());
+    }
+
+    if cli.command == Command::All || cli.command == Command::EcdsaSecp256k1 {
+        run_jobs::<ecdsa_secp256k1::Job>(&prover, &cli.out, &config, ecdsa_secp256k1::new_jobs());
+    }
+
+    if cli.command == Command::All || cli.command == Command::Ed25519 {
+        run_jobs::<ed25519::Job>(&prover, &cli.out, &config, ed25519::new_jobs());
+    }
+
+    if cli.command == Command::All || cli.command == Command::Fibonacci {
+        run_jobs::<fibonacci::Job>(&prover, &cli.out, &config, fibonacci::new_jobs());
+    }
+
+    if cli.command == Command::All || cli.command == Command::IterBlake3 {
+        run_jobs::<iter_blake3::Job>(&prover, &cli.out, &config, iter_blake3::new_jobs());
+    }
+
+    if cli.command == Command::All || cli.command == Command::IterKeccak {
+        run_jobs::<iter_keccak::Job>(&prover, &cli.out, &config, iter_keccak::new_jobs());
     }
 
     if cli.command == Command::All || cli.command == Command::IterSha2 {
-        run_jobs::<iter_sha2::Job>(&prover, &cli.out, iter_sha2::new_jobs());
+        run_jobs::<iter_sha2::Job>(&prover, &cli.out, &config, iter_sha2::new_jobs());
+    }
+
+    if cli.command == Command::All || cli.command == Command::Matmul {
+        run_jobs::<matmul::Job>(&prover, &cli.out, &config, matmul::new_jobs());
+    }
+
+    if cli.command == Command::All || cli.command == Command::MerklePathSha2 {
+        run_jobs::<merkle_path_sha2::Job>(&prover, &cli.out, &config, merkle_path_sha2::new_jobs());
+    }
+
+    if cli.command == Command::All || cli.command == Command::Sort {
+        run_jobs::<sort::Job>(&prover, &cli.out, &config, sort::new_jobs());
+    }
+
+    if cli.command == Command::All || cli.command == Command::RandomAccess {
+        run_jobs::<random_access::Job>(&prover, &cli.out, &config, random_access::new_jobs());
+    }
+
+    if cli.command == Command::All || cli.command == Command::JsonParse {
+        run_jobs::<json_parse::Job>(&prover, &cli.out, &config, json_parse::new_jobs());
+    }
+
+    if cli.command == Command::All || cli.command == Command::SmtUpdate {
+        run_jobs::<smt_update::Job>(&prover, &cli.out, &config, smt_update::new_jobs());
+    }
+
+    if cli.command == Command::All || cli.command == Command::EthHeaders {
+        run_jobs::<eth_headers::Job>(&prover, &cli.out, &config, eth_headers::new_jobs());
+    }
+
+    if cli.command == Command::All || cli.command == Command::ImageTransform {
+        run_jobs::<image_transform::Job>(&prover, &cli.out, &config, image_transform::new_jobs());
+    }
+
+    if cli.command == Command::All || cli.command == Command::U256Mulmod {
+        run_jobs::<u256_mulmod::Job>(&prover, &cli.out, &config, u256_mulmod::new_jobs());
+    }
+
+    if cli.command == Command::All || cli.command == Command::Rsa2048 {
+        run_jobs::<rsa_2048::Job>(&prover, &cli.out, &config, rsa_2048::new_jobs());
+    }
+
+    if cli.command == Command::All || cli.command == Command::Sudoku {
+        run_jobs::<sudoku::Job>(&prover, &cli.out, &config, sudoku::new_jobs());
+    }
+
+    if cli.command == Command::All || cli.command == Command::MerkleBuild {
+        run_jobs::<merkle_build::Job>(&prover, &cli.out, &config, merkle_build::new_jobs());
     }
 }
@@ -0,0 +1,84 @@
+use std::collections::HashMap;
+
+use risc0_zkvm::sha::{Digest, Impl, Sha256};
+
+/// A sparse binary Merkle tree over SHA-256 digests. Nodes are hashed with the
+/// SHA-256 compression function (`Sha256::hash_pair`), which the guest runs on
+/// the accelerator. Subtrees without leaves hash to a precomputed default for
+/// their level, so trees of any depth up to 64 can be built.
+pub struct MerkleTree {
+    depth: u32,
+    // Non-default nodes keyed by (level, index), where level 0 holds the leaves
+    nodes: HashMap<(u32, u64), Digest>,
+    // Root of an empty subtree for every level
+    empty: Vec<Digest>,
+}
+
+impl MerkleTree {
+    pub fn new(depth: u32) -> Self {
+        assert!(depth <= 64, "depth must be at most 64");
+
+        let mut empty = vec![Digest::default()];
+        for level in 0..depth as usize {
+            empty.push(*Impl::hash_pair(&empty[level], &empty[level]));
+        }
+
+        MerkleTree {
+            depth,
+            nodes: HashMap::new(),
+            empty,
+        }
+    }
+
+    pub fn root(&self) -> Digest {
+        self.node(self.depth, 0)
+    }
+
+    pub fn node(&self, level: u32, index: u64) -> Digest {
+        match self.nodes.get(&(level, index)) {
+            Some(node) => *node,
+            None => self.empty[level as usize],
+        }
+    }
+
+    /// Sets the leaf at `index` and recomputes the nodes on its path to the root.
+    pub fn insert(&mut self, index: u64, leaf: Digest) {
+        let mut index = index;
+        let mut node = leaf;
+        self.nodes.insert((0, index), node);
+
+        for level in 0..self.depth {
+            let sibling = self.node(level, index ^ 1);
+            node = if index & 1 == 0 {
+                *Impl::hash_pair(&node, &sibling)
+            } else {
+                *Impl::hash_pair(&sibling, &node)
+            };
+            index >>= 1;
+            self.nodes.insert((level + 1, index), node);
+        }
+    }
+
+    /// Returns the siblings on the path from the leaf at `index` to the root,
+    /// starting at the leaf level.
+    pub fn path(&self, index: u64) -> Vec<Digest> {
+        (0..self.depth)
+            .map(|level| self.node(level, (index >> level) ^ 1))
+            .collect()
+    }
+
+    /// Recomputes the root from a leaf and its path.
+    pub fn root_from_path(index: u64, leaf: &Digest, path: &[Digest]) -> Digest {
+        let mut index = index;
+        let mut node = *leaf;
+        for sibling in path {
+            node = if index & 1 == 0 {
+                *Impl::hash_pair(&node, sibling)
+            } else {
+                *Impl::hash_pair(sibling, &node)
+            };
+            index >>= 1;
+        }
+        node
+    }
+}
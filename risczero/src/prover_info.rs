@@ -0,0 +1,40 @@
+use rustbench::ProverInfo;
+
+/// Describes the RISC Zero zkVM with its default proof parameters.
+pub struct RiscZeroInfo;
+
+impl ProverInfo for RiscZeroInfo {
+    fn name(&self) -> String {
+        String::from("RISC Zero")
+    }
+
+    fn repository(&self) -> String {
+        String::from("https://github.com/risc0/risc0")
+    }
+
+    fn proof_system(&self) -> String {
+        String::from("STARK")
+    }
+
+    fn field(&self) -> String {
+        String::from("BabyBear (2^31 - 2^27 + 1)")
+    }
+
+    fn commitment_hash(&self) -> String {
+        String::from("SHA-256")
+    }
+
+    /// See https://github.com/risc0/risc0/#security
+    fn security_bits(&self) -> u32 {
+        100
+    }
+
+    fn post_quantum(&self) -> bool {
+        true
+    }
+
+    fn crate_version(&self) -> String {
+        // Locked version, see build.rs
+        String::from(env!("RISC0_ZKVM_VERSION"))
+    }
+}
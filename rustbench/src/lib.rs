@@ -5,13 +5,40 @@ use std::time::{Duration, Instant};
 use log::info;
 use serde::Serialize;
 
+/// Controls how often the prover and verifier are run for each job.
+pub struct RunConfig {
+    /// Number of times the proof is generated; the reported duration is the mean.
+    pub proof_runs: u32,
+    /// Verification is repeated until at least this much time has been spent.
+    pub min_verify_duration: Duration,
+}
+
+impl Default for RunConfig {
+    fn default() -> Self {
+        RunConfig {
+            proof_runs: 1,
+            min_verify_duration: Duration::from_secs(1),
+        }
+    }
+}
+
 pub struct Metrics {
     pub job_name: String,
     pub job_size: u32,
+    pub setup_duration: Duration,
     pub proof_duration: Duration,
     pub verify_duration: Duration,
     pub output_bytes: u32,
     pub proof_bytes: u32,
+    pub proof_runs: u32,
+    pub proof_variance_microsec2: f64,
+    pub verify_runs: u32,
+    pub verify_variance_microsec2: f64,
+    pub proof_options: String,
+    pub security_bits: Option<u32>,
+    pub proof_breakdown: Vec<(&'static str, u32)>,
+    pub job_params: String,
+    pub proof_throughput: f64,
 }
 
 impl Metrics {
@@ -19,23 +46,84 @@ impl Metrics {
         Metrics {
             job_name,
             job_size,
+            setup_duration: Duration::default(),
             proof_duration: Duration::default(),
             verify_duration: Duration::default(),
             output_bytes: 0,
             proof_bytes: 0,
+            proof_runs: 0,
+            proof_variance_microsec2: 0.0,
+            verify_runs: 0,
+            verify_variance_microsec2: 0.0,
+            proof_options: String::new(),
+            security_bits: None,
+            proof_breakdown: Vec::new(),
+            job_params: String::new(),
+            proof_throughput: 0.0,
         }
     }
 
     pub fn println(&self, prefix: &str) {
-        info!("{}job_name:           {:?}", prefix, &self.job_name);
-        info!("{}job_size:           {:?}", prefix, &self.job_size);
-        info!("{}proof_duration:     {:?}", prefix, &self.proof_duration);
-        info!("{}verify_duration:    {:?}", prefix, &self.verify_duration);
-        info!("{}output_bytes:       {:?}", prefix, &self.output_bytes);
-        info!("{}proof_bytes:        {:?}", prefix, &self.proof_bytes);
+        info!("{}job_name:           {:?}", prefix, self.job_name);
+        info!("{}job_size:           {:?}", prefix, self.job_size);
+        info!("{}proof_duration:     {:?}", prefix, self.proof_duration);
+        info!("{}verify_duration:    {:?}", prefix, self.verify_duration);
+        info!("{}output_bytes:       {:?}", prefix, self.output_bytes);
+        info!("{}proof_bytes:        {:?}", prefix, self.proof_bytes);
+        info!("{}proof_runs:         {:?}", prefix, self.proof_runs);
+        info!(
+            "{}proof_variance:     {:?}",
+            prefix, self.proof_variance_microsec2
+        );
+        info!("{}verify_runs:        {:?}", prefix, self.verify_runs);
+        info!(
+            "{}verify_variance:    {:?}",
+            prefix, self.verify_variance_microsec2
+        );
+        info!("{}setup_duration:     {:?}", prefix, self.setup_duration);
+        info!("{}proof_options:      {:?}", prefix, self.proof_options);
+        info!("{}security_bits:      {:?}", prefix, self.security_bits);
+        info!("{}proof_breakdown:    {:?}", prefix, self.proof_breakdown);
+        info!("{}job_params:         {:?}", prefix, self.job_params);
+        info!("{}proof_throughput:   {:?}", prefix, self.proof_throughput);
     }
 }
 
+/// Returns the mean of `samples` and their variance in microseconds squared.
+fn mean_and_variance(samples: &[Duration]) -> (Duration, f64) {
+    if samples.is_empty() {
+        return (Duration::default(), 0.0);
+    }
+
+    let n = samples.len() as f64;
+    let micros: Vec<f64> = samples.iter().map(|d| d.as_secs_f64() * 1e6).collect();
+    let mean = micros.iter().sum::<f64>() / n;
+    let variance = micros.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / n;
+
+    (Duration::from_secs_f64(mean / 1e6), variance)
+}
+
+/// Describes the proof system behind a prover. It is emitted with every result
+/// file so reports can compare provers at known security levels.
+pub trait ProverInfo {
+    /// Display name of the system, e.g. "Polygon Miden".
+    fn name(&self) -> String;
+    /// Link to the source repository of the system.
+    fn repository(&self) -> String;
+    /// Proof system family, e.g. "STARK".
+    fn proof_system(&self) -> String;
+    /// Field the constraints are expressed over.
+    fn field(&self) -> String;
+    /// Hash function used for trace and FRI commitments.
+    fn commitment_hash(&self) -> String;
+    /// Security level in bits for the proof options in use.
+    fn security_bits(&self) -> u32;
+    /// Whether the system is plausibly secure against quantum adversaries.
+    fn post_quantum(&self) -> bool;
+    /// Version of the prover crate the benchmarks are built against.
+    fn crate_version(&self) -> String;
+}
+
 pub trait Benchmark {
     const NAME: &'static str;
     type Spec;
@@ -46,25 +134,60 @@ pub trait Benchmark {
     fn output_size_bytes(output: &Self::ComputeOut, proof: &Self::ProofType) -> u32;
     fn proof_size_bytes(proof: &Self::ProofType) -> u32;
 
+    /// Sizes in bytes of the named components that make up the proof.
+    fn proof_size_breakdown(_proof: &Self::ProofType) -> Vec<(&'static str, u32)> {
+        Vec::new()
+    }
+
     fn new(spec: Self::Spec) -> Self;
 
     fn spec(&self) -> &Self::Spec;
 
+    /// Describes the parameters of the job that `job_size` does not capture.
+    fn job_params(&self) -> String {
+        String::new()
+    }
+
+    /// Describes the proof options the job is proven with.
+    fn proof_options(&self) -> String {
+        String::from("default")
+    }
+
+    /// Conjectured security level of `proof` in bits, if the prover reports one.
+    fn security_bits(_proof: &Self::ProofType) -> Option<u32> {
+        None
+    }
+
     fn host_compute(&mut self) -> Option<Self::ComputeOut> {
         None
     }
 
+    /// Prepares the state consumed by the next `guest_compute`. Runs before
+    /// every proof, outside the timed region.
+    fn guest_setup(&mut self) {}
+
     fn guest_compute(&mut self) -> (Self::ComputeOut, Self::ProofType);
     fn verify_proof(&self, output: &Self::ComputeOut, proof: &Self::ProofType) -> bool;
 
-    fn run(&mut self) -> Metrics {
+    fn run(&mut self, config: &RunConfig) -> Metrics {
         let mut metrics = Metrics::new(String::from(Self::NAME), Self::job_size(self.spec()));
 
         let (g_output, proof) = {
-            let start = Instant::now();
-            let result = self.guest_compute();
-            metrics.proof_duration = start.elapsed();
-            result
+            let mut samples = Vec::new();
+            let mut result = None;
+            for _ in 0..config.proof_runs.max(1) {
+                self.guest_setup();
+                let start = Instant::now();
+                result = Some(self.guest_compute());
+                samples.push(start.elapsed());
+            }
+            (metrics.proof_duration, metrics.proof_variance_microsec2) =
+                mean_and_variance(&samples);
+            metrics.proof_runs = samples.len() as u32;
+            // Units of job_size proven per second, e.g. signatures per second
+            metrics.proof_throughput =
+                metrics.job_size as f64 / metrics.proof_duration.as_secs_f64();
+            result.unwrap()
         };
 
         if let Some(h_output) = self.host_compute() {
@@ -73,15 +196,26 @@ pub trait Benchmark {
 
         metrics.output_bytes = Self::output_size_bytes(&g_output, &proof);
         metrics.proof_bytes = Self::proof_size_bytes(&proof);
+        metrics.proof_breakdown = Self::proof_size_breakdown(&proof);
+        metrics.proof_options = self.proof_options();
+        metrics.security_bits = Self::security_bits(&proof);
+        metrics.job_params = self.job_params();
 
-        let verify_proof = {
-            let start = Instant::now();
-            let result = self.verify_proof(&g_output, &proof);
-            metrics.verify_duration = start.elapsed();
-            result
-        };
-
-        assert_eq!(verify_proof, true);
+        // A single verification takes a few hundred microseconds, so we repeat it
+        // until enough time has passed for the mean to be meaningful.
+        {
+            let mut samples = Vec::new();
+            let total = Instant::now();
+            while samples.is_empty() || total.elapsed() < config.min_verify_duration {
+                let start = Instant::now();
+                let result = self.verify_proof(&g_output, &proof);
+                samples.push(start.elapsed());
+                assert!(result);
+            }
+            (metrics.verify_duration, metrics.verify_variance_microsec2) =
+                mean_and_variance(&samples);
+            metrics.verify_runs = samples.len() as u32;
+        }
 
         metrics
     }
@@ -91,6 +225,59 @@ pub fn init_logging() {
     env_logger::init();
 }
 
+#[derive(Serialize)]
+struct ProverInfoRow<'a> {
+    prover: &'a str,
+    name: String,
+    repository: String,
+    proof_system: String,
+    field: String,
+    commitment_hash: String,
+    security_bits: u32,
+    post_quantum: bool,
+    crate_version: String,
+}
+
+/// Logs `info` and appends it to the `.provers.csv` file next to `out_path`.
+pub fn write_prover_info(prover: &String, out_path: &Path, info: &dyn ProverInfo) {
+    let row = ProverInfoRow {
+        prover,
+        name: info.name(),
+        repository: info.repository(),
+        proof_system: info.proof_system(),
+        field: info.field(),
+        commitment_hash: info.commitment_hash(),
+        security_bits: info.security_bits(),
+        post_quantum: info.post_quantum(),
+        crate_version: info.crate_version(),
+    };
+
+    info!("");
+    info!("* prover:             {:?}", row.prover);
+    info!("* name:               {:?}", row.name);
+    info!("* repository:         {:?}", row.repository);
+    info!("* proof_system:       {:?}", row.proof_system);
+    info!("* field:              {:?}", row.field);
+    info!("* commitment_hash:    {:?}", row.commitment_hash);
+    info!("* security_bits:      {:?}", row.security_bits);
+    info!("* post_quantum:       {:?}", row.post_quantum);
+    info!("* crate_version:      {:?}", row.crate_version);
+
+    let info_path = out_path.with_extension("provers.csv");
+    let info_file_exists = info_path.exists();
+    let info_file = OpenOptions::new()
+        .append(true)
+        .create(true)
+        .open(&info_path)
+        .unwrap();
+    let mut out = csv::WriterBuilder::new()
+        .has_headers(!info_file_exists)
+        .from_writer(info_file);
+
+    out.serialize(row).expect("Could not serialize");
+    out.flush().expect("Could not flush");
+}
+
 #[derive(Serialize)]
 struct CsvRow<'a> {
     prover: &'a str,
@@ -99,11 +286,22 @@ struct CsvRow<'a> {
     proof_duration_microsec: u128,
     verify_duration_microsec: u128,
     proof_bytes: u32,
+    proof_runs: u32,
+    proof_variance_microsec2: f64,
+    verify_runs: u32,
+    verify_variance_microsec2: f64,
+    setup_duration_microsec: u128,
+    proof_options: &'a str,
+    security_bits: Option<u32>,
+    proof_breakdown: String,
+    job_params: &'a str,
+    proof_throughput_per_sec: f64,
 }
 
 pub fn run_jobs<B: Benchmark>(
     prover: &String,
     out_path: &PathBuf,
+    config: &RunConfig,
     specs: Vec<B::Spec>,
 ) -> Vec<Metrics> {
     info!("");
@@ -116,7 +314,6 @@ pub fn run_jobs<B: Benchmark>(
     let mut out = {
         let out_file_exists = Path::new(out_path).exists();
         let out_file = OpenOptions::new()
-            .write(true)
             .append(true)
             .create(true)
             .open(out_path)
@@ -129,21 +326,44 @@ pub fn run_jobs<B: Benchmark>(
     let mut all_metrics: Vec<Metrics> = Vec::new();
 
     for spec in specs {
-        let mut job = B::new(spec);
         let job_number = all_metrics.len();
 
         info!("");
         info!("+ begin job_number:   {} {}", job_number, B::NAME);
 
-        let job_metrics = job.run();
+        // Constructing the job compiles the program and loads images or keys,
+        // which is a one-time cost kept apart from the per-proof cost.
+        let (mut job, setup_duration) = {
+            let start = Instant::now();
+            let job = B::new(spec);
+            (job, start.elapsed())
+        };
+
+        let mut job_metrics = job.run(config);
+        job_metrics.setup_duration = setup_duration;
         job_metrics.println("+ ");
         out.serialize(CsvRow {
-            prover: &prover,
+            prover,
             job_name: &job_metrics.job_name,
             job_size: job_metrics.job_size,
             proof_duration_microsec: job_metrics.proof_duration.as_micros(),
             verify_duration_microsec: job_metrics.verify_duration.as_micros(),
             proof_bytes: job_metrics.proof_bytes,
+            proof_runs: job_metrics.proof_runs,
+            proof_variance_microsec2: job_metrics.proof_variance_microsec2,
+            verify_runs: job_metrics.verify_runs,
+            verify_variance_microsec2: job_metrics.verify_variance_microsec2,
+            setup_duration_microsec: job_metrics.setup_duration.as_micros(),
+            proof_options: &job_metrics.proof_options,
+            security_bits: job_metrics.security_bits,
+            proof_breakdown: job_metrics
+                .proof_breakdown
+                .iter()
+                .map(|(name, bytes)| format!("{}={}", name, bytes))
+                .collect::<Vec<String>>()
+                .join(" "),
+            job_params: &job_metrics.job_params,
+            proof_throughput_per_sec: job_metrics.proof_throughput,
         })
         .expect("Could not serialize");
 